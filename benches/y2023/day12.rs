@@ -0,0 +1,34 @@
+use advent_of_code_template::{input::load_input, solve_day, y2023::day12::Day12, Solution};
+use once_cell::sync::Lazy;
+
+static INPUT: Lazy<String> =
+    Lazy::new(|| load_input(2023, 12).unwrap_or_else(|err| panic!("{}", err)));
+
+fn parsing() {
+    Day12::parse_input(&INPUT).unwrap();
+}
+
+fn parsing_and_part_one() {
+    Day12::solve_part_one(&INPUT);
+}
+
+fn parsing_and_part_two() {
+    Day12::solve_part_two(&INPUT);
+}
+
+fn whole_solution() {
+    solve_day(&2023, &12, &INPUT).into_result().unwrap();
+}
+
+fn main() {
+    // iai counts the whole process, less a calibration run that only gets this far, so loading
+    // the input before handing over keeps it out of every benchmark
+    Lazy::force(&INPUT);
+    let benchmarks: &[&(&'static str, fn())] = &[
+        &("parsing", parsing),
+        &("parsing_and_part_one", parsing_and_part_one),
+        &("parsing_and_part_two", parsing_and_part_two),
+        &("whole_solution", whole_solution),
+    ];
+    iai::runner(benchmarks);
+}
@@ -0,0 +1,256 @@
+//! Heap allocation profiling.
+//!
+//! Build with `--features alloc-stats` to install [`CountingAllocator`] as the global allocator.
+//! Each phase of a solution is then measured with [`measure`], and the counts are shown next to
+//! the timings. Without the feature nothing is counted and [`measure`] returns `None`.
+//!
+//! The allocator can also hold the process to a memory budget, see [`limit_memory`].
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    io::{self, Write},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Live bytes that allocations are refused beyond, if the process is held to a budget.
+static LIVE_BYTES_LIMIT: AtomicUsize = AtomicUsize::new(usize::MAX);
+static BUDGET_EXCEEDED: AtomicBool = AtomicBool::new(false);
+
+/// Written to stderr when an allocation is refused for going over the budget, just before the
+/// process aborts.
+pub const OVER_BUDGET: &str = "Over the memory budget, refusing to allocate any more\n";
+
+/// Wraps the system allocator, keeping count of allocations and how much memory is in use.
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+impl CountingAllocator {
+    fn record_alloc(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES_ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn record_dealloc(size: usize) {
+        LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
+    }
+
+    /// Whether allocating `extra` more bytes would go over the budget.
+    fn over_budget(extra: usize) -> bool {
+        let live = LIVE_BYTES.load(Ordering::Relaxed);
+        live.saturating_add(extra) > LIVE_BYTES_LIMIT.load(Ordering::Relaxed)
+    }
+
+    /// Whether `extra` more bytes can be allocated without going over the budget. If not, the
+    /// overrun is reported, as the process is about to abort on the failed allocation.
+    fn within_budget(extra: usize) -> bool {
+        if !Self::over_budget(extra) {
+            return true;
+        }
+        // Only report once, and without allocating (which would land back here)
+        if !BUDGET_EXCEEDED.swap(true, Ordering::Relaxed) {
+            let _ = io::stderr().write_all(OVER_BUDGET.as_bytes());
+        }
+        false
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if !Self::within_budget(layout.size()) {
+            return std::ptr::null_mut();
+        }
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        if !Self::within_budget(layout.size()) {
+            return std::ptr::null_mut();
+        }
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            Self::record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        Self::record_dealloc(layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        if !Self::within_budget(new_size.saturating_sub(layout.size())) {
+            return std::ptr::null_mut();
+        }
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // Count a realloc as freeing the old block and allocating the new one
+            Self::record_dealloc(layout.size());
+            Self::record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Whether the counting allocator is installed.
+pub const fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Heap usage while running a piece of code.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AllocStats {
+    /// Number of allocations (including reallocations).
+    pub allocations: usize,
+    /// Total bytes requested across all allocations.
+    pub bytes_allocated: usize,
+    /// Largest amount of extra memory in use at once, relative to when measuring started.
+    pub peak_live_bytes: usize,
+}
+
+impl fmt::Display for AllocStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocs, {} allocated, {} peak",
+            self.allocations,
+            format_bytes(self.bytes_allocated),
+            format_bytes(self.peak_live_bytes)
+        )
+    }
+}
+
+/// Heap usage of each phase of a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PhaseAllocs {
+    pub parse: AllocStats,
+    pub part_one: AllocStats,
+    pub part_two: AllocStats,
+}
+
+/// Run `f`, recording the heap usage of everything it (and any threads it starts) does.
+/// Returns `None` for the stats if the counting allocator isn't installed.
+/// Measurements use process-wide counters, so don't nest calls or measure on several threads at once.
+pub fn measure<R>(f: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    if !enabled() {
+        return (f(), None);
+    }
+    let start_allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let start_bytes = BYTES_ALLOCATED.load(Ordering::Relaxed);
+    let start_live = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(start_live, Ordering::Relaxed);
+
+    let result = f();
+
+    let stats = AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - start_allocations,
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::Relaxed) - start_bytes,
+        peak_live_bytes: PEAK_LIVE_BYTES
+            .load(Ordering::Relaxed)
+            .saturating_sub(start_live),
+    };
+    (result, Some(stats))
+}
+
+/// Hold the process to using at most `budget` bytes more than is in use now, until the returned
+/// guard is dropped. Needs the counting allocator, so does nothing without the `alloc-stats`
+/// feature.
+///
+/// An allocator can't unwind, so going over the budget can't just fail the phase that did it.
+/// Instead the allocation is refused, which aborts the process, after writing [`OVER_BUDGET`] to
+/// stderr. So only hold a process to a budget when it's running nothing but the day in question,
+/// which is what [`isolate`](crate::isolate) starts one for.
+pub fn limit_memory(budget: Option<usize>) -> MemoryLimit {
+    if let Some(budget) = budget {
+        let live = LIVE_BYTES.load(Ordering::Relaxed);
+        LIVE_BYTES_LIMIT.store(live.saturating_add(budget), Ordering::Relaxed);
+    }
+    MemoryLimit
+}
+
+/// Lifts the budget set by [`limit_memory`] when dropped.
+#[must_use = "The budget is lifted as soon as this is dropped"]
+pub struct MemoryLimit;
+
+impl Drop for MemoryLimit {
+    fn drop(&mut self) {
+        LIVE_BYTES_LIMIT.store(usize::MAX, Ordering::Relaxed);
+    }
+}
+
+/// Format a number of bytes in whichever binary unit keeps it readable.
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+
+    #[test]
+    fn check_memory_budget() {
+        let live = LIVE_BYTES.load(Ordering::Relaxed);
+        let limit = limit_memory(Some(usize::MAX / 2));
+        assert!(!CountingAllocator::over_budget(1024));
+        assert!(CountingAllocator::over_budget(usize::MAX / 2 + live + 1));
+        drop(limit);
+        assert_eq!(LIVE_BYTES_LIMIT.load(Ordering::Relaxed), usize::MAX);
+        assert!(!CountingAllocator::over_budget(usize::MAX / 2 + live + 1));
+    }
+
+    #[test]
+    #[cfg(not(feature = "alloc-stats"))]
+    fn check_nothing_measured_without_feature() {
+        assert_eq!(measure(|| vec![0u8; 100].len()), (100, None));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn check_allocations_are_counted() {
+        let (_, stats) = measure(|| {
+            let first = vec![0u8; 4096];
+            drop(first);
+            let second = vec![0u8; 1024];
+            second.len()
+        });
+        let stats = stats.unwrap();
+        // Other tests run concurrently and share the counters, so only check lower bounds
+        assert!(stats.allocations >= 2);
+        assert!(stats.bytes_allocated >= 5120);
+        assert!(stats.peak_live_bytes > 0);
+    }
+}
@@ -0,0 +1,310 @@
+//! Known-correct answers, used to spot when a change to a solution alters its result.
+//!
+//! Answers are kept in `answers.toml` next to the inputs, one table per day:
+//! ```toml
+//! [1]
+//! part_one = "54304"
+//! part_two = "54418"
+//! ```
+//! Either part may be left out until it's been solved.
+
+use std::{collections::BTreeMap, fmt, fs, io, path::Path};
+
+use serde::Deserialize;
+
+use crate::{error::Part, DayResult, Parts};
+
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+pub struct DayAnswers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl DayAnswers {
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::Parse => None,
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    days: BTreeMap<i32, DayAnswers>,
+}
+
+impl Answers {
+    /// Load recorded answers, treating a missing file as having no answers yet.
+    pub fn load(path: &Path) -> Result<Self, AnswersError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(err) => Err(AnswersError::Io(err)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, AnswersError> {
+        let tables: BTreeMap<String, DayAnswers> =
+            toml::from_str(contents).map_err(AnswersError::Toml)?;
+        let mut days = BTreeMap::new();
+        for (key, answers) in tables {
+            let day = key
+                .parse::<i32>()
+                .map_err(|_| AnswersError::BadDay(key.clone()))?;
+            days.insert(day, answers);
+        }
+        Ok(Answers { days })
+    }
+
+    pub fn get(&self, day: i32, part: Part) -> Option<&str> {
+        self.days.get(&day).and_then(|answers| answers.get(part))
+    }
+
+    /// Compare a fresh answer with the recorded one.
+    pub fn check(&self, day: i32, part: Part, answer: &str) -> CheckStatus {
+        match self.get(day, part) {
+            None => CheckStatus::New,
+            Some(expected) if expected == answer => CheckStatus::Pass,
+            Some(_) => CheckStatus::Fail,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    Fail,
+    /// There's no recorded answer to compare against yet.
+    New,
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Pad so the status column lines up in tables
+        let status = match self {
+            CheckStatus::Pass => "PASS",
+            CheckStatus::Fail => "FAIL",
+            CheckStatus::New => "NEW",
+        };
+        f.pad(status)
+    }
+}
+
+/// One line of a `--check` run.
+#[derive(Clone, Debug)]
+pub struct CheckRow {
+    pub day: i32,
+    pub part: Part,
+    pub status: CheckStatus,
+    /// The answer produced by this run, or the error if the solution failed.
+    pub actual: String,
+    pub expected: Option<String>,
+}
+
+/// The results of checking a run against the recorded answers.
+#[derive(Clone, Debug, Default)]
+pub struct CheckReport {
+    rows: Vec<CheckRow>,
+}
+
+impl CheckReport {
+    /// Record the answers for the parts of a day that were run, or the error that stopped them
+    /// from being produced.
+    pub fn record(&mut self, answers: &Answers, parts: Parts, result: &DayResult) {
+        let day = result.day;
+        for part in [Part::One, Part::Two] {
+            if !parts.includes(part) {
+                continue;
+            }
+            let expected = answers.get(day, part).map(str::to_string);
+            let (status, actual) = match (result.answers.answer(part), &result.error) {
+                (Some(actual), _) => (answers.check(day, part, actual), actual.to_string()),
+                (None, Some(err)) => (CheckStatus::Fail, format!("error: {}", err)),
+                (None, None) => continue,
+            };
+            self.rows.push(CheckRow {
+                day,
+                part,
+                status,
+                actual,
+                expected,
+            });
+        }
+    }
+
+    pub fn rows(&self) -> &[CheckRow] {
+        &self.rows
+    }
+
+    pub fn any_failed(&self) -> bool {
+        self.rows.iter().any(|row| row.status == CheckStatus::Fail)
+    }
+}
+
+impl fmt::Display for CheckReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let actual_width = self
+            .rows
+            .iter()
+            .map(|row| row.actual.len())
+            .chain(["Answer".len()])
+            .max()
+            .unwrap_or_default();
+        writeln!(
+            f,
+            "{:>3}  {:<6}  {:<6}  {:<actual_width$}  Expected",
+            "Day", "Part", "Result", "Answer"
+        )?;
+        for row in &self.rows {
+            writeln!(
+                f,
+                "{:>3}  {:<6}  {:<6}  {:<actual_width$}  {}",
+                row.day,
+                row.part,
+                row.status,
+                row.actual,
+                row.expected.as_deref().unwrap_or("-")
+            )?;
+        }
+        let count = |status| self.rows.iter().filter(|row| row.status == status).count();
+        write!(
+            f,
+            "{} passed, {} failed, {} new",
+            count(CheckStatus::Pass),
+            count(CheckStatus::Fail),
+            count(CheckStatus::New)
+        )
+    }
+}
+
+#[derive(Debug)]
+pub enum AnswersError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    BadDay(String),
+}
+
+impl fmt::Display for AnswersError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Io(err) => write!(f, "Couldn't read recorded answers: {}", err),
+            AnswersError::Toml(err) => write!(f, "Couldn't parse recorded answers: {}", err),
+            AnswersError::BadDay(key) => {
+                write!(f, "Recorded answers have a table for unknown day '{}'", key)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::AocError, TimedAnswers};
+
+    const ANSWERS: &str = r#"
+[1]
+part_one = "142"
+part_two = "281"
+
+[3]
+part_one = "4361"
+"#;
+
+    #[test]
+    fn check_answers_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, Part::Two), Some("281"));
+        assert_eq!(answers.get(3, Part::One), Some("4361"));
+        assert_eq!(answers.get(3, Part::Two), None);
+        assert_eq!(answers.get(4, Part::One), None);
+    }
+
+    #[test]
+    fn check_answers_compare() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.check(1, Part::One, "142"), CheckStatus::Pass);
+        assert_eq!(answers.check(1, Part::One, "143"), CheckStatus::Fail);
+        assert_eq!(answers.check(3, Part::Two, "467835"), CheckStatus::New);
+    }
+
+    fn succeeded(day: i32, answers: TimedAnswers) -> DayResult {
+        DayResult {
+            day,
+            answers,
+            error: None,
+        }
+    }
+
+    #[test]
+    fn check_report_flags_failures_and_errors() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let mut report = CheckReport::default();
+        let timed = TimedAnswers {
+            part_one: Some("142".to_string()),
+            part_two: Some("281".to_string()),
+            timings: Default::default(),
+            allocations: None,
+            skewed: false,
+        };
+        report.record(&answers, Parts::Both, &succeeded(1, timed));
+        assert!(!report.any_failed());
+        report.record(
+            &answers,
+            Parts::Both,
+            &DayResult::failed(3, AocError::new("bad input")),
+        );
+        assert!(report.any_failed());
+        assert_eq!(report.rows()[2].actual, "error: bad input");
+    }
+
+    #[test]
+    fn check_report_only_has_parts_that_ran() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let mut report = CheckReport::default();
+        let timed = TimedAnswers {
+            part_one: None,
+            part_two: Some("281".to_string()),
+            timings: Default::default(),
+            allocations: None,
+            skewed: false,
+        };
+        report.record(&answers, Parts::Two, &succeeded(1, timed));
+        report.record(
+            &answers,
+            Parts::One,
+            &DayResult::failed(3, AocError::new("bad input")),
+        );
+        assert_eq!(report.rows().len(), 2);
+        assert_eq!(report.rows()[0].part, Part::Two);
+        assert_eq!(report.rows()[1].part, Part::One);
+    }
+
+    #[test]
+    fn check_report_keeps_answers_before_an_error() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let mut report = CheckReport::default();
+        let result = DayResult {
+            day: 1,
+            answers: TimedAnswers {
+                part_one: Some("142".to_string()),
+                ..Default::default()
+            },
+            error: Some(AocError::new("TIMEOUT after 1.00 s")),
+        };
+        report.record(&answers, Parts::Both, &result);
+        assert_eq!(report.rows()[0].status, CheckStatus::Pass);
+        assert_eq!(report.rows()[1].status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn check_bad_day_key_is_rejected() {
+        assert!(matches!(
+            Answers::parse("[one]\npart_one = \"1\""),
+            Err(AnswersError::BadDay(_))
+        ));
+    }
+}
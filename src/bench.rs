@@ -0,0 +1,212 @@
+//! In-process wall-clock benchmarking.
+//!
+//! Complements the iai benches (which need valgrind and only count instructions) by repeatedly
+//! timing each phase of a solution and summarising the spread of the measurements.
+//! Phases are split the same way as the iai benches: `parse_input`, `part_one` and `part_two`.
+
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::{
+    error::{AocResult, Part},
+    report::format_duration,
+    Solution,
+};
+
+/// How long to spend benchmarking each day.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchConfig {
+    /// Time spent running the solution before measuring, so caches and the allocator are warm.
+    /// The solution always runs at least once.
+    pub warm_up: Duration,
+    /// Maximum number of measurements to take.
+    pub samples: usize,
+    /// Stop taking measurements after this long, even if fewer than `samples` have been taken.
+    /// At least one measurement is always taken, so slow solutions still get a result.
+    pub max_time: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        BenchConfig {
+            warm_up: Duration::from_secs(1),
+            samples: 100,
+            max_time: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Summary of repeated measurements of a single phase.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub std_dev: Duration,
+}
+
+impl Stats {
+    /// Summarise a set of measurements. Returns `None` if there aren't any.
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let mean = sorted.iter().sum::<Duration>() / n as u32;
+        let variance = if n > 1 {
+            sorted
+                .iter()
+                .map(|s| (s.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (n - 1) as f64
+        } else {
+            0.0
+        };
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // Nearest-rank percentile
+        let p95 = sorted[((n as f64 * 0.95).ceil() as usize).clamp(1, n) - 1];
+        Some(Stats {
+            samples: n,
+            min: sorted[0],
+            median,
+            mean,
+            p95,
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+        })
+    }
+}
+
+/// Benchmark results for each phase of a day's solution.
+#[derive(Clone, Copy, Debug)]
+pub struct BenchResult {
+    pub parse: Stats,
+    pub part_one: Stats,
+    pub part_two: Stats,
+}
+
+impl fmt::Display for BenchResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "{:<12} {:>7} {:>10} {:>10} {:>10} {:>10} {:>10}",
+            "Phase", "Samples", "Min", "Median", "Mean", "p95", "Std dev"
+        )?;
+        for (name, stats) in [
+            ("parse_input", &self.parse),
+            ("part_one", &self.part_one),
+            ("part_two", &self.part_two),
+        ] {
+            writeln!(
+                f,
+                "{:<12} {:>7} {:>10} {:>10} {:>10} {:>10} {:>10}",
+                name,
+                stats.samples,
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.mean),
+                format_duration(stats.p95),
+                format_duration(stats.std_dev),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Repeatedly time each phase of a solution.
+/// Each part runs on freshly parsed input so that anything part one changes can't affect part two,
+/// the extra parse isn't included in either part's measurement.
+pub fn bench_solution<T: Solution>(
+    input_lines: &str,
+    config: &BenchConfig,
+) -> AocResult<BenchResult> {
+    let run_once = || -> AocResult<(Duration, Duration, Duration)> {
+        let start_time = Instant::now();
+        let mut input =
+            T::parse_input(input_lines).map_err(|err| err.during(T::DAY, Part::Parse))?;
+        let parse_time = start_time.elapsed();
+        let start_time = Instant::now();
+        T::part_one(&mut input).map_err(|err| err.during(T::DAY, Part::One))?;
+        let p1_time = start_time.elapsed();
+
+        let mut input =
+            T::parse_input(input_lines).map_err(|err| err.during(T::DAY, Part::Parse))?;
+        let start_time = Instant::now();
+        T::part_two(&mut input).map_err(|err| err.during(T::DAY, Part::Two))?;
+        let p2_time = start_time.elapsed();
+        Ok((parse_time, p1_time, p2_time))
+    };
+
+    let warm_up_start = Instant::now();
+    loop {
+        run_once()?;
+        if warm_up_start.elapsed() >= config.warm_up {
+            break;
+        }
+    }
+
+    let (mut parse, mut part_one, mut part_two) = (vec![], vec![], vec![]);
+    let measure_start = Instant::now();
+    while parse.len() < config.samples.max(1) {
+        let (parse_time, p1_time, p2_time) = run_once()?;
+        parse.push(parse_time);
+        part_one.push(p1_time);
+        part_two.push(p2_time);
+        if measure_start.elapsed() >= config.max_time {
+            break;
+        }
+    }
+
+    Ok(BenchResult {
+        parse: Stats::from_samples(&parse).expect("At least one sample is always taken"),
+        part_one: Stats::from_samples(&part_one).expect("At least one sample is always taken"),
+        part_two: Stats::from_samples(&part_two).expect("At least one sample is always taken"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2023::day00::Day00;
+
+    #[test]
+    fn check_stats() {
+        let samples = [5, 1, 4, 2, 3].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_millis(3));
+        assert_eq!(stats.mean, Duration::from_millis(3));
+        assert_eq!(stats.p95, Duration::from_millis(5));
+        // Sample standard deviation of 1..=5 is sqrt(2.5)
+        assert_eq!(stats.std_dev.as_micros(), 1581);
+    }
+
+    #[test]
+    fn check_stats_even_samples_and_empty() {
+        let samples = [1, 2, 3, 4].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn check_bench_takes_requested_samples() {
+        let config = BenchConfig {
+            warm_up: Duration::ZERO,
+            samples: 3,
+            max_time: Duration::from_secs(60),
+        };
+        let result = bench_solution::<Day00>("1, 2\n4, 3", &config).unwrap();
+        assert_eq!(result.parse.samples, 3);
+        assert_eq!(result.part_two.samples, 3);
+    }
+}
@@ -0,0 +1,146 @@
+//! Printing results to the terminal as days are run.
+//! Solutions return a [`DayResult`] without printing anything, so this is what the runner uses
+//! to show them.
+
+use crate::{alloc_stats::AllocStats, error::Part, input::InputSource, DayResult};
+
+/// Prints each day's answers as it finishes.
+#[derive(Clone, Copy, Debug)]
+pub struct Reporter {
+    /// Whether to show how long each phase took, and how much it allocated when that's recorded.
+    /// Timings aren't worth showing when days run in parallel.
+    pub show_timings: bool,
+}
+
+impl Default for Reporter {
+    fn default() -> Self {
+        Reporter { show_timings: true }
+    }
+}
+
+impl Reporter {
+    /// Announce a day, along with where its input came from if it isn't the usual place.
+    pub fn start_day(&self, day: i32, title: &str, custom_input: Option<&InputSource>) {
+        match custom_input {
+            Some(source) => println!("Day {}: {} (input from {})", day, title, source),
+            None => println!("Day {}: {}", day, title),
+        }
+    }
+
+    /// Show the answers from the phases that finished, and the error that stopped the rest.
+    pub fn finish_day(&self, result: &DayResult) {
+        println!("----------");
+        for line in self.lines(result) {
+            println!("{}", line);
+        }
+    }
+
+    fn lines(&self, result: &DayResult) -> Vec<String> {
+        let answers = &result.answers;
+        let allocations = answers.allocations;
+        let show_timings = self.show_timings && !answers.skewed;
+        let note = |micros: u128, stats: Option<AllocStats>| {
+            if !show_timings {
+                return "".to_string();
+            }
+            match stats {
+                Some(stats) => format!(" ({} μs, {})", micros, stats),
+                None => format!(" ({} μs)", micros),
+            }
+        };
+        let mut lines = vec![];
+        if self.show_timings && answers.skewed {
+            lines.push("Not timed, a phase that timed out earlier is still running".to_string());
+        }
+        if show_timings && result.completed(Part::Parse) {
+            lines.push(format!(
+                "Parsing...{}",
+                note(
+                    answers.timings.parse.as_micros(),
+                    allocations.map(|allocs| allocs.parse)
+                )
+            ));
+        }
+        if let Some(answer) = &answers.part_one {
+            lines.push(format!(
+                "Part 1: {}{}",
+                answer,
+                note(
+                    answers.timings.part_one.as_micros(),
+                    allocations.map(|allocs| allocs.part_one)
+                )
+            ));
+        }
+        if let Some(answer) = &answers.part_two {
+            lines.push(format!(
+                "Part 2: {}{}",
+                answer,
+                note(
+                    answers.timings.part_two.as_micros(),
+                    allocations.map(|allocs| allocs.part_two)
+                )
+            ));
+        }
+        if let Some(err) = &result.error {
+            lines.push(format!("Failed - {}", err));
+        }
+        lines
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{error::AocError, TimedAnswers, Timings};
+
+    #[test]
+    fn check_finished_day_lines() {
+        let result = DayResult {
+            day: 8,
+            answers: TimedAnswers {
+                part_one: Some("6".to_string()),
+                part_two: None,
+                timings: Timings {
+                    parse: Duration::from_micros(12),
+                    part_one: Duration::from_micros(340),
+                    part_two: Duration::ZERO,
+                },
+                allocations: None,
+                skewed: false,
+            },
+            error: Some(AocError::new("Never reached ZZZ").during(8, Part::Two)),
+        };
+        assert_eq!(
+            Reporter::default().lines(&result),
+            [
+                "Parsing... (12 μs)",
+                "Part 1: 6 (340 μs)",
+                "Failed - day 8, part 2: Never reached ZZZ"
+            ]
+        );
+        let quiet = Reporter {
+            show_timings: false,
+        };
+        assert_eq!(
+            quiet.lines(&result),
+            ["Part 1: 6", "Failed - day 8, part 2: Never reached ZZZ"]
+        );
+        let mut skewed = result.clone();
+        skewed.answers.skewed = true;
+        assert_eq!(
+            Reporter::default().lines(&skewed),
+            [
+                "Not timed, a phase that timed out earlier is still running",
+                "Part 1: 6",
+                "Failed - day 8, part 2: Never reached ZZZ"
+            ]
+        );
+        let unparsed = DayResult::failed(8, AocError::new("Bad").during(8, Part::Parse));
+        assert_eq!(
+            Reporter::default().lines(&unparsed),
+            ["Failed - day 8, parsing: Bad"]
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt,
+    num::ParseIntError,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// The stage of a solution an error came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Part {
+    Parse,
+    One,
+    Two,
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::Parse => write!(f, "parsing"),
+            Part::One => write!(f, "part 1"),
+            Part::Two => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Error returned by a solution when it can't make sense of its input.
+/// Solutions only need to fill in what they know (usually the message and where in the input
+/// things went wrong), the runner adds the day and part before reporting it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AocError {
+    pub day: Option<i32>,
+    pub part: Option<Part>,
+    /// 1-based line of the input the error was found on.
+    pub line: Option<usize>,
+    /// 1-based column of the input the error was found at.
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    pub fn new(message: impl Into<String>) -> Self {
+        AocError {
+            day: None,
+            part: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at(self, line: usize, column: usize) -> Self {
+        self.at_line(line).at_column(column)
+    }
+
+    /// Record the line the error happened on, unless a more specific one is already known.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line.get_or_insert(line);
+        self
+    }
+
+    /// Record the column the error happened at, unless a more specific one is already known.
+    pub fn at_column(mut self, column: usize) -> Self {
+        self.column.get_or_insert(column);
+        self
+    }
+
+    /// Record which day and part of the solution the error came from.
+    pub fn during(self, day: i32, part: Part) -> Self {
+        self.in_day(day).in_part(part)
+    }
+
+    pub fn in_day(mut self, day: i32) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+
+    pub fn in_part(mut self, part: Part) -> Self {
+        self.part.get_or_insert(part);
+        self
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut location = vec![];
+        if let Some(day) = self.day {
+            location.push(format!("day {}", day));
+        }
+        if let Some(part) = self.part {
+            location.push(part.to_string());
+        }
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => location.push(format!("line {}:{}", line, column)),
+            (Some(line), None) => location.push(format!("line {}", line)),
+            (None, Some(column)) => location.push(format!("column {}", column)),
+            (None, None) => (),
+        }
+        if location.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", location.join(", "), self.message)
+        }
+    }
+}
+
+impl std::error::Error for AocError {}
+
+impl From<ParseIntError> for AocError {
+    fn from(err: ParseIntError) -> Self {
+        AocError::new(format!("Invalid number ({})", err))
+    }
+}
+
+thread_local! {
+    /// Whether this thread is inside [`catch_panic`], in which case panics are recorded quietly.
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Run `f`, turning a panic into an error message (including where it happened) instead of
+/// unwinding any further. For running solutions that assume things about their input, so one bad
+/// assumption doesn't stop the rest of the run.
+pub fn catch_panic<R>(f: impl FnOnce() -> R) -> Result<R, String> {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(Cell::get) {
+                return default_hook(info);
+            }
+            let payload = panic_payload(info.payload());
+            let message = match info.location() {
+                Some(location) => format!("panicked at {}: {}", location, payload),
+                None => format!("panicked: {}", payload),
+            };
+            PANIC_MESSAGE.with(|panic_message| *panic_message.borrow_mut() = Some(message));
+        }));
+    });
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(was_catching));
+    result.map_err(|payload| {
+        PANIC_MESSAGE
+            .with(|panic_message| panic_message.borrow_mut().take())
+            // Panics passed on from another thread don't go through the hook again
+            .unwrap_or_else(|| format!("panicked: {}", panic_payload(payload.as_ref())))
+    })
+}
+
+/// The message a panic was started with.
+pub(crate) fn panic_payload(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Box<dyn Any>".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_error_display() {
+        let err = AocError::new("Unexpected char 'x'")
+            .at(3, 7)
+            .in_part(Part::Parse)
+            .in_day(10);
+        assert_eq!(
+            err.to_string(),
+            "day 10, parsing, line 3:7: Unexpected char 'x'"
+        );
+    }
+
+    #[test]
+    fn check_innermost_location_wins() {
+        let err = AocError::new("Bad")
+            .at_column(4)
+            .at(2, 1)
+            .in_part(Part::Two);
+        assert_eq!((err.line, err.column), (Some(2), Some(4)));
+        assert_eq!(err.to_string(), "part 2, line 2:4: Bad");
+    }
+
+    #[test]
+    fn check_panics_are_caught() {
+        assert_eq!(catch_panic(|| 5), Ok(5));
+        let message = catch_panic(|| -> i32 { panic!("GRID_SIZE is {}", 140) }).unwrap_err();
+        assert!(message.starts_with("panicked at src/error.rs:"));
+        assert!(message.ends_with(": GRID_SIZE is 140"));
+        let message = catch_panic(|| {
+            let worker = std::thread::spawn(|| panic!("Never reached ZZZ"));
+            panic::resume_unwind(worker.join().unwrap_err())
+        })
+        .unwrap_err();
+        assert_eq!(message, "panicked: Never reached ZZZ");
+    }
+}
@@ -0,0 +1,369 @@
+//! A rectangular grid of cells, sized by whatever input it's parsed from, for all the puzzles
+//! that come as a map of characters. Cells are addressed by `(x, y)`, with `(0, 0)` the first
+//! char of the input and `y` increasing down the lines.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+
+use crate::{
+    error::{AocError, AocResult},
+    point::{Dir4, Dir8, Point},
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    /// Row by row, so cell `(x, y)` is at `y * width + x`.
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` x `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parse each char of `text` into a cell with `cell`, one row per line. Every line has to be
+    /// the same length, and errors from `cell` are reported at the char that caused them.
+    pub fn parse(text: &str, mut cell: impl FnMut(char) -> AocResult<T>) -> AocResult<Self> {
+        let mut width = None;
+        let mut cells = Vec::with_capacity(text.len());
+        let mut height = 0;
+        for (y, line) in text.lines().enumerate() {
+            let row_start = cells.len();
+            for (x, c) in line.chars().enumerate() {
+                cells.push(cell(c).map_err(|err| err.at(y + 1, x + 1))?);
+            }
+            let row_width = cells.len() - row_start;
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(AocError::new(format!(
+                    "Row is {} long, but the ones before it are {}",
+                    row_width,
+                    width.unwrap()
+                ))
+                .at_line(y + 1));
+            }
+            height += 1;
+        }
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, coord: (usize, usize)) -> Option<&T> {
+        self.contains(coord)
+            .then(|| &self.cells[coord.1 * self.width + coord.0])
+    }
+
+    pub fn get_mut(&mut self, coord: (usize, usize)) -> Option<&mut T> {
+        if self.contains(coord) {
+            Some(&mut self.cells[coord.1 * self.width + coord.0])
+        } else {
+            None
+        }
+    }
+
+    /// The cells directly above, right of, below and left of `coord` that are in the grid.
+    pub fn neighbours4(&self, coord: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.steps(coord, Dir4::ALL.map(Dir8::from))
+    }
+
+    /// The cells around `coord`, including diagonals, that are in the grid.
+    pub fn neighbours8(&self, coord: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.steps(coord, Dir8::ALL)
+    }
+
+    fn steps<const N: usize>(
+        &self,
+        coord: (usize, usize),
+        dirs: [Dir8; N],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let point = Point::from(coord);
+        dirs.into_iter().filter_map(move |dir| {
+            let coord = point.step(dir).to_coord()?;
+            self.contains(coord).then_some(coord)
+        })
+    }
+
+    /// Every coordinate, row by row.
+    pub fn coords(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell with its coordinate, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    /// The cells of row `y`, or `None` if it's below the bottom of the grid.
+    pub fn row(&self, y: usize) -> Option<&[T]> {
+        (y < self.height).then(|| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on 0, but a grid with no columns has no cells to chunk anyway
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The cells of column `x` from top to bottom, or `None` if it's off the right of the grid.
+    pub fn column(&self, x: usize) -> Option<impl Iterator<Item = &T>> {
+        (x < self.width).then(|| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.cells.iter().skip(x).step_by(self.width))
+    }
+
+    /// The coordinate of the first cell, row by row, that matches `predicate`.
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(coord, _)| coord)
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Build a `width` x `height` grid, taking each cell from `from(x, y)`.
+    fn rearranged(
+        &self,
+        width: usize,
+        height: usize,
+        from: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[from(x, y)].clone())
+            .collect();
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Swap rows and columns, so the first row becomes the first column.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |x, y| (y, x))
+    }
+
+    /// Turn a quarter turn clockwise, so the first row becomes the last column.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(height, self.width, |x, y| (y, height - 1 - x))
+    }
+
+    /// Turn a quarter turn anticlockwise, so the first row becomes the first column, upside down.
+    pub fn rotate_anticlockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(self.height, width, |x, y| (width - 1 - y, x))
+    }
+
+    /// Mirror left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.rearranged(width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirror top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.rearranged(self.width, height, |x, y| (x, height - 1 - y))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: (usize, usize)) -> &T {
+        self.get(coord).unwrap_or_else(|| {
+            panic!(
+                "{:?} is outside the {}x{} grid",
+                coord, self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, coord: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{:?} is outside the {}x{} grid", coord, width, height))
+    }
+}
+
+/// Points off the top or left of the grid panic, just like ones off the bottom or right.
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match point.to_coord() {
+            Some(coord) => &self[coord],
+            None => panic!("{:?} is outside the grid", point),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match point.to_coord() {
+            Some(coord) => &mut self[coord],
+            None => panic!("{:?} is outside the grid", point),
+        }
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> AocResult<Self> {
+        Grid::parse(s, Ok)
+    }
+}
+
+/// One line per row, without a newline after the last, e.g. to print a map for debugging.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "ab.\n.#c\n";
+
+    #[test]
+    fn check_parse_and_display() {
+        let grid = EXAMPLE.parse::<Grid<char>>().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 1)], 'c');
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.find(|c| *c == '#'), Some((1, 1)));
+        assert_eq!(grid.to_string(), EXAMPLE.trim_end());
+
+        let digits = Grid::parse("12\n34", |c| {
+            c.to_digit(10).ok_or_else(|| AocError::new("Not a digit"))
+        })
+        .unwrap();
+        assert_eq!(digits.iter().map(|(_, d)| d).sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn check_parse_errors() {
+        assert_eq!(
+            Grid::parse("12\n3x", |c| c
+                .to_digit(10)
+                .ok_or_else(|| AocError::new("Not a digit")))
+            .unwrap_err()
+            .to_string(),
+            "line 2:2: Not a digit"
+        );
+        assert_eq!(
+            "ab\nabc".parse::<Grid<char>>().unwrap_err().to_string(),
+            "line 2: Row is 3 long, but the ones before it are 2"
+        );
+        assert_eq!("".parse::<Grid<char>>().unwrap().rows().count(), 0);
+    }
+
+    #[test]
+    fn check_neighbours() {
+        let grid = Grid::new(3, 2, ());
+        assert_eq!(
+            grid.neighbours4((0, 0)).collect::<Vec<_>>(),
+            [(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            grid.neighbours8((1, 1)).collect::<Vec<_>>(),
+            [(1, 0), (2, 0), (2, 1), (0, 1), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn check_rows_and_columns() {
+        let grid = EXAMPLE.parse::<Grid<char>>().unwrap();
+        assert_eq!(grid.row(1), Some(['.', '#', 'c'].as_slice()));
+        assert_eq!(grid.row(2), None);
+        assert_eq!(
+            grid.column(1).map(|column| column.collect()),
+            Some("b#".to_string())
+        );
+        assert!(grid.column(3).is_none());
+        let columns = grid
+            .columns()
+            .map(|column| column.collect::<String>())
+            .collect::<Vec<_>>();
+        assert_eq!(columns, ["a.", "b#", ".c"]);
+    }
+
+    #[test]
+    fn check_transformations() {
+        let grid = EXAMPLE.parse::<Grid<char>>().unwrap();
+        assert_eq!(grid.transpose().to_string(), "a.\nb#\n.c");
+        assert_eq!(grid.rotate_clockwise().to_string(), ".a\n#b\nc.");
+        assert_eq!(grid.rotate_anticlockwise().to_string(), ".c\nb#\na.");
+        assert_eq!(grid.flip_horizontal().to_string(), ".ba\nc#.");
+        assert_eq!(grid.flip_vertical().to_string(), ".#c\nab.");
+        assert_eq!(grid.rotate_clockwise().rotate_anticlockwise(), grid);
+    }
+}
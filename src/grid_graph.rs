@@ -0,0 +1,199 @@
+//! Turning a [`Grid`] into a petgraph [`Graph`], with a node for each interesting cell and edges
+//! between neighbours that connect, so the puzzle can be solved with petgraph's algorithms.
+//!
+//! ```
+//! # use advent_of_code_template::{grid::Grid, grid_graph::{GraphBuilder, GridGraph}};
+//! let maze: Grid<char> = "S.#\n#..".parse().unwrap();
+//! let graph: GridGraph<char, ()> = GraphBuilder::new(&maze).nodes(|c| *c != '#').build();
+//! let start = graph.node((0, 0)).unwrap();
+//! assert_eq!(graph.graph.neighbors(start).count(), 1);
+//! ```
+
+use petgraph::{graph::NodeIndex, EdgeType, Graph, Undirected};
+
+use crate::{
+    grid::Grid,
+    point::{Dir4, Dir8, Point},
+};
+
+/// A graph built from a grid, remembering which cell each node came from.
+#[derive(Clone)]
+pub struct GridGraph<N, E, Ty = Undirected> {
+    pub graph: Graph<N, E, Ty>,
+    node_indices: Grid<Option<NodeIndex>>,
+    /// The cell each node came from, by node index.
+    coords: Vec<(usize, usize)>,
+}
+
+impl<N, E, Ty: EdgeType> GridGraph<N, E, Ty> {
+    /// The node for the cell at `coord`, if that cell has one.
+    pub fn node(&self, coord: (usize, usize)) -> Option<NodeIndex> {
+        self.node_indices.get(coord).copied().flatten()
+    }
+
+    /// The cell `node` came from.
+    pub fn coord(&self, node: NodeIndex) -> (usize, usize) {
+        self.coords[node.index()]
+    }
+
+    /// Width of the grid the graph was built from.
+    pub fn width(&self) -> usize {
+        self.node_indices.width()
+    }
+
+    /// Height of the grid the graph was built from.
+    pub fn height(&self) -> usize {
+        self.node_indices.height()
+    }
+}
+
+/// Whether a cell joins up with its neighbour towards a direction.
+type Connects<'g, T> = Box<dyn Fn(Dir8, &T, &T) -> bool + 'g>;
+
+/// Which cells of a grid become nodes, and which neighbouring nodes get an edge between them.
+/// By default every cell is a node, and each is joined to the cells above, below, left and right.
+pub struct GraphBuilder<'g, T> {
+    grid: &'g Grid<T>,
+    include: Box<dyn Fn(&T) -> bool + 'g>,
+    dirs: &'static [Dir8],
+    connects: Connects<'g, T>,
+}
+
+impl<'g, T> GraphBuilder<'g, T> {
+    const ORTHOGONAL: [Dir8; 4] = [Dir8::North, Dir8::East, Dir8::South, Dir8::West];
+
+    pub fn new(grid: &'g Grid<T>) -> Self {
+        GraphBuilder {
+            grid,
+            include: Box::new(|_| true),
+            dirs: &Self::ORTHOGONAL,
+            connects: Box::new(|_, _, _| true),
+        }
+    }
+
+    /// Only make nodes for cells that match `include`, e.g. leaving out walls.
+    pub fn nodes(mut self, include: impl Fn(&T) -> bool + 'g) -> Self {
+        self.include = Box::new(include);
+        self
+    }
+
+    /// Only join a cell to the next one over towards a direction if `connects(dir, from, to)`.
+    /// In an undirected graph, the cells are joined if either side connects to the other.
+    pub fn connect(mut self, connects: impl Fn(Dir4, &T, &T) -> bool + 'g) -> Self {
+        self.dirs = &Self::ORTHOGONAL;
+        self.connects = Box::new(move |dir, from, to| {
+            Dir4::try_from(dir).is_ok_and(|dir| connects(dir, from, to))
+        });
+        self
+    }
+
+    /// Like [`GraphBuilder::connect`], but diagonal neighbours can be joined too.
+    pub fn connect_diagonally(mut self, connects: impl Fn(Dir8, &T, &T) -> bool + 'g) -> Self {
+        self.dirs = &Dir8::ALL;
+        self.connects = Box::new(connects);
+        self
+    }
+
+    /// Build the graph, with a copy of each cell as its node's weight.
+    pub fn build<Ty: EdgeType>(self) -> GridGraph<T, (), Ty>
+    where
+        T: Clone,
+    {
+        self.build_weighted(|_, _| ())
+    }
+
+    /// Build the graph, weighting the edge between neighbouring cells `from` and `to` with
+    /// `weight(from, to)`.
+    pub fn build_weighted<E, Ty: EdgeType>(
+        self,
+        mut weight: impl FnMut((usize, usize), (usize, usize)) -> E,
+    ) -> GridGraph<T, E, Ty>
+    where
+        T: Clone,
+    {
+        let grid = self.grid;
+        let mut graph = Graph::default();
+        let mut node_indices = Grid::new(grid.width(), grid.height(), None);
+        let mut coords = vec![];
+        for (coord, cell) in grid.iter().filter(|(_, cell)| (self.include)(cell)) {
+            node_indices[coord] = Some(graph.add_node(cell.clone()));
+            coords.push(coord);
+        }
+
+        for &from in &coords {
+            let from_node = node_indices[from].unwrap();
+            for dir in self.dirs {
+                let Some(to) = Point::from(from).step(*dir).to_coord() else {
+                    continue;
+                };
+                let Some(&Some(to_node)) = node_indices.get(to) else {
+                    continue;
+                };
+                if (self.connects)(*dir, &grid[from], &grid[to]) {
+                    graph.update_edge(from_node, to_node, weight(from, to));
+                }
+            }
+        }
+
+        GridGraph {
+            graph,
+            node_indices,
+            coords,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use petgraph::{algo::dijkstra, Directed};
+
+    use super::*;
+
+    #[test]
+    fn check_nodes_and_coords() {
+        let grid: Grid<char> = "#.#\n...".parse().unwrap();
+        let graph: GridGraph<char, ()> = GraphBuilder::new(&grid).nodes(|c| *c == '.').build();
+        assert_eq!(graph.graph.node_count(), 4);
+        assert_eq!(graph.graph.edge_count(), 3);
+        assert_eq!(graph.node((0, 0)), None);
+        let node = graph.node((2, 1)).unwrap();
+        assert_eq!(graph.coord(node), (2, 1));
+        assert_eq!((graph.width(), graph.height()), (3, 2));
+    }
+
+    #[test]
+    fn check_connections() {
+        // Slopes can only be walked down in the direction they point
+        let grid: Grid<char> = "..>.\n.#v.".parse().unwrap();
+        let slopes = |dir: Dir4, from: &char, _: &char| match Dir4::from_char(*from) {
+            Ok(slope) => slope == dir,
+            Err(_) => true,
+        };
+        let graph: GridGraph<char, (), Directed> = GraphBuilder::new(&grid)
+            .nodes(|c| *c != '#')
+            .connect(slopes)
+            .build();
+        let slope = graph.node((2, 0)).unwrap();
+        let neighbours = graph.graph.neighbors(slope).map(|node| graph.coord(node));
+        assert_eq!(neighbours.collect::<Vec<_>>(), [(3, 0)]);
+
+        let graph: GridGraph<char, ()> = GraphBuilder::new(&grid)
+            .connect_diagonally(|_, _, _| true)
+            .build();
+        assert_eq!(
+            graph.graph.neighbors(graph.node((0, 0)).unwrap()).count(),
+            3
+        );
+    }
+
+    #[test]
+    fn check_weights() {
+        // Moving down a row costs more than moving along one
+        let grid = Grid::new(3, 3, ());
+        let graph: GridGraph<(), usize> =
+            GraphBuilder::new(&grid).build_weighted(|from, to| if from.1 == to.1 { 1 } else { 10 });
+        let start = graph.node((0, 0)).unwrap();
+        let distances = dijkstra(&graph.graph, start, None, |edge| *edge.weight());
+        assert_eq!(distances[&graph.node((2, 2)).unwrap()], 22);
+    }
+}
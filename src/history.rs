@@ -0,0 +1,511 @@
+//! A record of every timed run and benchmark, for spotting regressions between commits.
+//!
+//! Each measurement is appended as a line of JSON to [`HISTORY_FILE`], tagged with the git commit
+//! it was taken at. [`History::compare`] then lines up the measurements from two commits.
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bench::BenchResult, error::Part, iai_report::IaiStats, registry::DEFAULT_YEAR, TimedAnswers,
+};
+
+/// Where measurements are recorded. Kept under `target/` so `cargo clean` clears it.
+pub const HISTORY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/bench-history.jsonl");
+
+/// What kind of measurement an entry is. Only measurements of the same kind are compared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    /// A single timed run of the solution, in nanoseconds.
+    Run,
+    /// The median from `--bench=wall`, in nanoseconds.
+    Wall,
+    /// The instruction count from iai.
+    Iai,
+}
+
+impl Source {
+    fn format_value(&self, value: u64) -> String {
+        match self {
+            Source::Run | Source::Wall => {
+                crate::report::format_duration(Duration::from_nanos(value))
+            }
+            Source::Iai => value.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let source = match self {
+            Source::Run => "run",
+            Source::Wall => "wall",
+            Source::Iai => "iai",
+        };
+        f.pad(source)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Short hash of the commit the measurement was taken at, with `-dirty` appended if there
+    /// were uncommitted changes.
+    pub commit: String,
+    /// `debug` or `release`, timings from the two aren't comparable.
+    pub profile: String,
+    /// Entries recorded before years were tracked are for the default year.
+    #[serde(default = "default_year")]
+    pub year: i32,
+    pub day: i32,
+    /// `parse_input`, `part_one` or `part_two`, or the bench function's name for iai.
+    pub phase: String,
+    pub source: Source,
+    pub value: u64,
+}
+
+fn default_year() -> i32 {
+    DEFAULT_YEAR
+}
+
+/// Builds the entries for a single invocation, which all share a year, commit and timestamp.
+#[derive(Clone, Debug)]
+pub struct Recorder {
+    year: i32,
+    commit: String,
+    timestamp: u64,
+    entries: Vec<HistoryEntry>,
+}
+
+impl Recorder {
+    pub fn new(year: i32, commit: String, timestamp: u64) -> Self {
+        Recorder {
+            year,
+            commit,
+            timestamp,
+            entries: vec![],
+        }
+    }
+
+    /// A recorder for measurements of `year` taken at the current commit and time.
+    pub fn now(year: i32) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|since| since.as_secs())
+            .unwrap_or_default();
+        Recorder::new(year, git_commit(), timestamp)
+    }
+
+    fn push(&mut self, profile: &str, day: i32, phase: &str, source: Source, value: u64) {
+        self.entries.push(HistoryEntry {
+            timestamp: self.timestamp,
+            commit: self.commit.clone(),
+            profile: profile.to_string(),
+            year: self.year,
+            day,
+            phase: phase.to_string(),
+            source,
+            value,
+        });
+    }
+
+    /// Record the time taken by each phase of a run, skipping parts that weren't run.
+    pub fn record_run(&mut self, day: i32, answers: &TimedAnswers) {
+        let timings = &answers.timings;
+        for (phase, time, part) in [
+            ("parse_input", timings.parse, Part::Parse),
+            ("part_one", timings.part_one, Part::One),
+            ("part_two", timings.part_two, Part::Two),
+        ] {
+            if part == Part::Parse || answers.answer(part).is_some() {
+                self.push(build_profile(), day, phase, Source::Run, nanos(time));
+            }
+        }
+    }
+
+    pub fn record_wall(&mut self, day: i32, result: &BenchResult) {
+        for (phase, stats) in [
+            ("parse_input", &result.parse),
+            ("part_one", &result.part_one),
+            ("part_two", &result.part_two),
+        ] {
+            self.push(
+                build_profile(),
+                day,
+                phase,
+                Source::Wall,
+                nanos(stats.median),
+            );
+        }
+    }
+
+    pub fn record_iai(&mut self, day: i32, benches: &[(String, IaiStats)]) {
+        for (function, stats) in benches {
+            // The benches are always built by `cargo bench`, whatever this was built with
+            self.push("bench", day, function, Source::Iai, stats.instructions);
+        }
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Append everything recorded so far to the history file, creating it if needed.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if self.entries.is_empty() {
+            return Ok(());
+        }
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let mut lines = String::new();
+        for entry in &self.entries {
+            lines += &serde_json::to_string(entry).expect("History entries are always valid JSON");
+            lines.push('\n');
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)?
+            .write_all(lines.as_bytes())
+    }
+}
+
+fn build_profile() -> &'static str {
+    if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// The short hash of the commit checked out, or `unknown` if git isn't available.
+pub fn git_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(env!("CARGO_MANIFEST_DIR"))
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    let Some(commit) = git(&["rev-parse", "--short", "HEAD"]) else {
+        return "unknown".to_string();
+    };
+    match git(&["status", "--porcelain", "--untracked-files=no"]) {
+        Some(changes) if !changes.is_empty() => format!("{}-dirty", commit),
+        _ => commit,
+    }
+}
+
+/// Every measurement recorded so far, oldest first.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    entries: Vec<HistoryEntry>,
+}
+
+impl History {
+    /// Load the history, treating a missing file as an empty history.
+    pub fn load(path: &Path) -> Result<Self, HistoryError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => History::parse(&contents),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(History::default()),
+            Err(err) => Err(HistoryError::Io(err)),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, HistoryError> {
+        let entries = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                serde_json::from_str(line).map_err(|err| HistoryError::BadEntry(idx + 1, err))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(History { entries })
+    }
+
+    pub fn entries(&self) -> &[HistoryEntry] {
+        &self.entries
+    }
+
+    /// Find the most recent commit matching `name`, which may be a prefix of the recorded hash
+    /// (or the full hash of a recorded short one).
+    pub fn resolve_commit(&self, name: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .rev()
+            .map(|entry| entry.commit.as_str())
+            .find(|commit| *commit == name || commit.starts_with(name) || name.starts_with(commit))
+    }
+
+    /// The most recent commit, and the one before it, if there are that many.
+    pub fn latest_commits(&self) -> (Option<&str>, Option<&str>) {
+        let mut commits = self.entries.iter().rev().map(|entry| entry.commit.as_str());
+        let Some(latest) = commits.next() else {
+            return (None, None);
+        };
+        (Some(latest), commits.find(|commit| *commit != latest))
+    }
+
+    /// Compare the latest measurements of every phase measured at both commits.
+    /// A phase has regressed if it got worse by more than `threshold` percent.
+    pub fn compare(&self, baseline: &str, candidate: &str, threshold: f64) -> Comparison {
+        let latest_at = |commit: &str| {
+            let mut values = BTreeMap::new();
+            for entry in self.entries.iter().filter(|entry| entry.commit == commit) {
+                let key = (
+                    entry.year,
+                    entry.day,
+                    entry.source,
+                    entry.profile.clone(),
+                    entry.phase.clone(),
+                );
+                values.insert(key, entry.value);
+            }
+            values
+        };
+        let before = latest_at(baseline);
+        let after = latest_at(candidate);
+        let rows = before
+            .into_iter()
+            .filter_map(|(key, baseline_value)| {
+                let candidate_value = *after.get(&key)?;
+                let (year, day, source, profile, phase) = key;
+                let change = if baseline_value == 0 {
+                    0.0
+                } else {
+                    (candidate_value as f64 - baseline_value as f64) / baseline_value as f64 * 100.0
+                };
+                Some(ComparisonRow {
+                    year,
+                    day,
+                    source,
+                    profile,
+                    phase,
+                    baseline: baseline_value,
+                    candidate: candidate_value,
+                    change,
+                    regressed: change > threshold,
+                })
+            })
+            .collect();
+        Comparison {
+            baseline: baseline.to_string(),
+            candidate: candidate.to_string(),
+            threshold,
+            rows,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ComparisonRow {
+    pub year: i32,
+    pub day: i32,
+    pub source: Source,
+    pub profile: String,
+    pub phase: String,
+    pub baseline: u64,
+    pub candidate: u64,
+    /// Percentage change from the baseline, positive if it got slower.
+    pub change: f64,
+    pub regressed: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Comparison {
+    pub baseline: String,
+    pub candidate: String,
+    pub threshold: f64,
+    pub rows: Vec<ComparisonRow>,
+}
+
+impl Comparison {
+    pub fn any_regressed(&self) -> bool {
+        self.rows.iter().any(|row| row.regressed)
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Comparing {} against baseline {} (threshold {}%)",
+            self.candidate, self.baseline, self.threshold
+        )?;
+        if self.rows.is_empty() {
+            return write!(f, "Nothing was measured at both commits");
+        }
+        let phase_width = self
+            .rows
+            .iter()
+            .map(|row| row.phase.len())
+            .chain(["Phase".len()])
+            .max()
+            .unwrap_or_default();
+        writeln!(
+            f,
+            "{:>4}  {:>3}  {:<6}  {:<7}  {:<phase_width$}  {:>12}  {:>12}  {:>8}",
+            "Year", "Day", "Source", "Profile", "Phase", "Baseline", "Candidate", "Change"
+        )?;
+        for row in &self.rows {
+            writeln!(
+                f,
+                "{:>4}  {:>3}  {:<6}  {:<7}  {:<phase_width$}  {:>12}  {:>12}  {:>+7.1}%{}",
+                row.year,
+                row.day,
+                row.source,
+                row.profile,
+                row.phase,
+                row.source.format_value(row.baseline),
+                row.source.format_value(row.candidate),
+                row.change,
+                if row.regressed { "  REGRESSED" } else { "" }
+            )?;
+        }
+        let regressions = self.rows.iter().filter(|row| row.regressed).count();
+        write!(f, "{} of {} phases regressed", regressions, self.rows.len())
+    }
+}
+
+#[derive(Debug)]
+pub enum HistoryError {
+    Io(io::Error),
+    BadEntry(usize, serde_json::Error),
+}
+
+impl fmt::Display for HistoryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HistoryError::Io(err) => write!(f, "Couldn't read benchmark history: {}", err),
+            HistoryError::BadEntry(line, err) => {
+                write!(f, "Bad benchmark history entry on line {}: {}", line, err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for HistoryError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Timings;
+
+    fn history() -> History {
+        let mut lines = String::new();
+        for (commit, part_one, part_two) in [("aaa1111", 100, 1000), ("bbb2222", 120, 500)] {
+            let mut recorder = Recorder::new(2023, commit.to_string(), 0);
+            recorder.record_run(
+                3,
+                &TimedAnswers {
+                    part_one: Some("1".to_string()),
+                    part_two: Some("2".to_string()),
+                    timings: Timings {
+                        parse: Duration::from_nanos(10),
+                        part_one: Duration::from_nanos(part_one),
+                        part_two: Duration::from_nanos(part_two),
+                    },
+                    allocations: None,
+                    skewed: false,
+                },
+            );
+            for entry in recorder.entries() {
+                lines += &serde_json::to_string(entry).unwrap();
+                lines.push('\n');
+            }
+        }
+        History::parse(&lines).unwrap()
+    }
+
+    #[test]
+    fn check_history_round_trip() {
+        let history = history();
+        assert_eq!(history.entries().len(), 6);
+        assert_eq!(history.entries()[4].phase, "part_one");
+        assert_eq!(history.entries()[4].value, 120);
+        assert_eq!(history.entries()[4].source, Source::Run);
+    }
+
+    #[test]
+    fn check_parts_not_run_are_not_recorded() {
+        let mut recorder = Recorder::new(2023, "aaa1111".to_string(), 0);
+        recorder.record_run(
+            3,
+            &TimedAnswers {
+                part_one: Some("1".to_string()),
+                part_two: None,
+                timings: Default::default(),
+                allocations: None,
+                skewed: false,
+            },
+        );
+        let phases = recorder
+            .entries()
+            .iter()
+            .map(|entry| entry.phase.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(phases, ["parse_input", "part_one"]);
+    }
+
+    #[test]
+    fn check_commits_resolve() {
+        let history = history();
+        assert_eq!(history.latest_commits(), (Some("bbb2222"), Some("aaa1111")));
+        assert_eq!(history.resolve_commit("aaa"), Some("aaa1111"));
+        assert_eq!(history.resolve_commit("bbb2222ffffff"), Some("bbb2222"));
+        assert_eq!(history.resolve_commit("ccc"), None);
+    }
+
+    #[test]
+    fn check_compare_flags_regressions() {
+        let comparison = history().compare("aaa1111", "bbb2222", 10.0);
+        assert_eq!(comparison.rows.len(), 3);
+        let part_one = &comparison.rows[1];
+        assert_eq!(part_one.phase, "part_one");
+        assert_eq!(part_one.change, 20.0);
+        assert!(part_one.regressed);
+        let part_two = &comparison.rows[2];
+        assert_eq!(part_two.change, -50.0);
+        assert!(!part_two.regressed);
+        assert!(comparison.any_regressed());
+        assert!(!history()
+            .compare("aaa1111", "bbb2222", 25.0)
+            .any_regressed());
+    }
+
+    #[test]
+    fn check_entries_without_a_year_are_for_the_default_year() {
+        let history = History::parse(
+            r#"{"timestamp":0,"commit":"aaa1111","profile":"debug","day":1,"phase":"part_one","source":"run","value":5}"#,
+        )
+        .unwrap();
+        assert_eq!(history.entries()[0].year, DEFAULT_YEAR);
+    }
+
+    #[test]
+    fn check_bad_entry_is_reported() {
+        assert!(matches!(
+            History::parse("{\"day\": 1}\n"),
+            Err(HistoryError::BadEntry(1, _))
+        ));
+    }
+}
@@ -0,0 +1,224 @@
+//! Collecting the output of the iai benches into a single table.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::report::ReportFormat;
+
+/// Counts reported by iai for a single bench function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct IaiStats {
+    pub instructions: u64,
+    pub l1_accesses: u64,
+    pub l2_accesses: u64,
+    pub ram_accesses: u64,
+    pub estimated_cycles: u64,
+}
+
+/// Pull the stats for each bench function out of iai's output.
+/// Functions are returned in the order they appear, lines that aren't part of a bench's results
+/// (e.g. cargo's build output) are ignored.
+pub fn parse_iai_output(output: &str) -> Vec<(String, IaiStats)> {
+    let mut benches: Vec<(String, IaiStats)> = vec![];
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        if !line.starts_with(char::is_whitespace) {
+            // iai prints each function's name on its own line before its stats
+            benches.push((line.trim().to_string(), IaiStats::default()));
+            continue;
+        }
+        let Some((key, rest)) = line.trim().split_once(':') else {
+            continue;
+        };
+        // Values can be followed by a change since the last run, e.g. "1234 (+0.5%)"
+        let Some(Ok(value)) = rest.split_whitespace().next().map(str::parse::<u64>) else {
+            continue;
+        };
+        let Some((_, stats)) = benches.last_mut() else {
+            continue;
+        };
+        match key {
+            "Instructions" => stats.instructions = value,
+            "L1 Accesses" => stats.l1_accesses = value,
+            "L2 Accesses" => stats.l2_accesses = value,
+            "RAM Accesses" => stats.ram_accesses = value,
+            "Estimated Cycles" => stats.estimated_cycles = value,
+            _ => (),
+        }
+    }
+    // Anything without an instruction count wasn't really a bench
+    benches.retain(|(_, stats)| stats.instructions != 0);
+    benches
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct IaiRow {
+    pub day: i32,
+    pub function: String,
+    #[serde(flatten)]
+    pub stats: IaiStats,
+}
+
+/// iai results for every benchmarked day.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct IaiReport {
+    rows: Vec<IaiRow>,
+}
+
+impl IaiReport {
+    pub fn push(&mut self, day: i32, benches: Vec<(String, IaiStats)>) {
+        self.rows
+            .extend(benches.into_iter().map(|(function, stats)| IaiRow {
+                day,
+                function,
+                stats,
+            }));
+    }
+
+    pub fn rows(&self) -> &[IaiRow] {
+        &self.rows
+    }
+
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Json => {
+                serde_json::to_string_pretty(self).expect("Report is always valid JSON")
+            }
+            ReportFormat::Csv => {
+                let mut csv =
+                    "day,function,instructions,l1_accesses,l2_accesses,ram_accesses,estimated_cycles\n"
+                        .to_string();
+                for row in &self.rows {
+                    csv += &format!(
+                        "{},{},{},{},{},{},{}\n",
+                        row.day,
+                        row.function,
+                        row.stats.instructions,
+                        row.stats.l1_accesses,
+                        row.stats.l2_accesses,
+                        row.stats.ram_accesses,
+                        row.stats.estimated_cycles
+                    );
+                }
+                csv
+            }
+            ReportFormat::Markdown => {
+                let mut table = "| Day | Function | Instructions | L1 Accesses | L2 Accesses | RAM Accesses | Estimated Cycles |\n\
+                    |---:|---|---:|---:|---:|---:|---:|\n"
+                    .to_string();
+                for row in &self.rows {
+                    table += &format!(
+                        "| {} | {} | {} | {} | {} | {} | {} |\n",
+                        row.day,
+                        row.function,
+                        row.stats.instructions,
+                        row.stats.l1_accesses,
+                        row.stats.l2_accesses,
+                        row.stats.ram_accesses,
+                        row.stats.estimated_cycles
+                    );
+                }
+                table
+            }
+        }
+    }
+}
+
+impl fmt::Display for IaiReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let function_width = self
+            .rows
+            .iter()
+            .map(|row| row.function.len())
+            .chain(["Function".len()])
+            .max()
+            .unwrap_or_default();
+        writeln!(
+            f,
+            "{:>3}  {:<function_width$}  {:>14}  {:>14}  {:>12}  {:>12}  {:>16}",
+            "Day",
+            "Function",
+            "Instructions",
+            "L1 Accesses",
+            "L2 Accesses",
+            "RAM Accesses",
+            "Estimated Cycles"
+        )?;
+        for row in &self.rows {
+            writeln!(
+                f,
+                "{:>3}  {:<function_width$}  {:>14}  {:>14}  {:>12}  {:>12}  {:>16}",
+                row.day,
+                row.function,
+                row.stats.instructions,
+                row.stats.l1_accesses,
+                row.stats.l2_accesses,
+                row.stats.ram_accesses,
+                row.stats.estimated_cycles
+            )?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OUTPUT: &str = "parsing
+  Instructions:               46208
+  L1 Accesses:                63066
+  L2 Accesses:                   30
+  RAM Accesses:                 161
+  Estimated Cycles:           68851
+
+parsing_and_part_one
+  Instructions:              187353 (+0.123456%)
+  L1 Accesses:               253456 (No change)
+  L2 Accesses:                   48 (-4.000000%)
+  RAM Accesses:                 180
+  Estimated Cycles:          259996
+
+";
+
+    #[test]
+    fn check_parse_iai_output() {
+        let benches = parse_iai_output(OUTPUT);
+        assert_eq!(benches.len(), 2);
+        assert_eq!(
+            benches[0],
+            (
+                "parsing".to_string(),
+                IaiStats {
+                    instructions: 46208,
+                    l1_accesses: 63066,
+                    l2_accesses: 30,
+                    ram_accesses: 161,
+                    estimated_cycles: 68851,
+                }
+            )
+        );
+        assert_eq!(benches[1].0, "parsing_and_part_one");
+        assert_eq!(benches[1].1.instructions, 187353);
+        assert_eq!(benches[1].1.l2_accesses, 48);
+    }
+
+    #[test]
+    fn check_unrelated_output_is_ignored() {
+        assert!(parse_iai_output("Benchmarking day 3...\nwarning: something\n").is_empty());
+    }
+
+    #[test]
+    fn check_report_json() {
+        let mut report = IaiReport::default();
+        report.push(3, parse_iai_output(OUTPUT));
+        let json: serde_json::Value =
+            serde_json::from_str(&report.render(ReportFormat::Json)).unwrap();
+        assert_eq!(json["rows"][1]["day"], 3);
+        assert_eq!(json["rows"][1]["function"], "parsing_and_part_one");
+        assert_eq!(json["rows"][1]["instructions"], 187353);
+    }
+}
@@ -0,0 +1,356 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Directory puzzle inputs are read from unless told otherwise.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// Name of the file in each year's input directory holding known-correct answers.
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+/// Where to find the puzzle input for each day.
+/// Inputs are stored in a directory per year, one file per day named by the day number,
+/// e.g. `inputs/2023/5`.
+#[derive(Clone, Debug)]
+pub struct InputDir {
+    root: PathBuf,
+}
+
+impl InputDir {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        InputDir { root: root.into() }
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// The path the input for `day` of `year` is expected at.
+    pub fn path_for(&self, year: i32, day: i32) -> PathBuf {
+        self.locate(Path::new(&year.to_string()).join(day.to_string()))
+    }
+
+    /// The path of a year's recorded answers file, which lives alongside its inputs.
+    pub fn answers_path(&self, year: i32) -> PathBuf {
+        self.locate(Path::new(&year.to_string()).join(ANSWERS_FILE))
+    }
+
+    /// Relative directories are looked up from the current directory first and then from the
+    /// crate root, so `cargo run`, `cargo test` and `cargo bench` all find the same files.
+    fn locate(&self, file_name: impl AsRef<Path>) -> PathBuf {
+        let path = self.root.join(file_name);
+        if path.is_relative() && !path.exists() {
+            let from_crate_root = Path::new(env!("CARGO_MANIFEST_DIR")).join(&path);
+            if from_crate_root.exists() {
+                return from_crate_root;
+            }
+        }
+        path
+    }
+
+    /// Read the input for `day` of `year` into a string.
+    pub fn load(&self, year: i32, day: i32) -> Result<String, InputError> {
+        let path = self.path_for(year, day);
+        if path.is_dir() {
+            return Err(InputError::Directory { year, day, path });
+        }
+        fs::read_to_string(&path).map_err(|source| match source.kind() {
+            io::ErrorKind::NotFound => InputError::Missing { year, day, path },
+            _ => InputError::Unreadable {
+                year,
+                day,
+                path,
+                source,
+            },
+        })
+    }
+}
+
+/// Where a day's input was read from.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// Parse a command line argument, where `-` means stdin.
+    pub fn from_arg(arg: &Path) -> Self {
+        if arg == Path::new("-") {
+            InputSource::Stdin
+        } else {
+            InputSource::File(arg.to_path_buf())
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => io::read_to_string(io::stdin()),
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+impl InputDir {
+    /// Every input available for `day` of `year`, for checking a solution works on more than one.
+    /// The day's path can either be a single input, or a directory of `*.txt` inputs (e.g. ones
+    /// collected from the team), which are returned in name order.
+    pub fn inputs_for(&self, year: i32, day: i32) -> Result<Vec<PathBuf>, InputError> {
+        let path = self.path_for(year, day);
+        let unreadable = |source| InputError::Unreadable {
+            year,
+            day,
+            path: path.clone(),
+            source,
+        };
+        if path.is_file() {
+            return Ok(vec![path]);
+        }
+        if !path.is_dir() {
+            return Err(InputError::Missing { year, day, path });
+        }
+        let mut inputs = vec![];
+        for entry in fs::read_dir(&path).map_err(unreadable)? {
+            let input = entry.map_err(unreadable)?.path();
+            if input.is_file() && input.extension().is_some_and(|ext| ext == "txt") {
+                inputs.push(input);
+            }
+        }
+        if inputs.is_empty() {
+            return Err(InputError::Missing { year, day, path });
+        }
+        inputs.sort();
+        Ok(inputs)
+    }
+}
+
+impl Default for InputDir {
+    fn default() -> Self {
+        InputDir::new(DEFAULT_INPUT_DIR)
+    }
+}
+
+/// Read the input for `day` of `year` from the default input directory, tidied up as the runner
+/// would with [`normalise`].
+pub fn load_input(year: i32, day: i32) -> Result<String, InputError> {
+    InputDir::default()
+        .load(year, day)
+        .map(|input| normalise(&input).0)
+}
+
+/// Something [`normalise`] had to change in an input.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fix {
+    ByteOrderMark,
+    CrLf,
+    TrailingWhitespace,
+}
+
+impl fmt::Display for Fix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Fix::ByteOrderMark => write!(f, "stripped a byte order mark"),
+            Fix::CrLf => write!(f, "converted Windows (CRLF) line endings"),
+            Fix::TrailingWhitespace => write!(f, "trimmed trailing whitespace"),
+        }
+    }
+}
+
+/// Put an input into the form it's downloaded in, so solutions don't need to cope with the ways
+/// saving it can change it: strip a byte order mark, turn CRLF line endings into LF, and remove
+/// whitespace from the end, leaving the single newline the input ends with.
+/// Whitespace at the end of earlier lines is left alone, as some puzzles pad lines to a width.
+/// Returns the tidied input and what had to be changed, if anything.
+pub fn normalise(input: &str) -> (String, Vec<Fix>) {
+    let mut fixes = vec![];
+    let input = match input.strip_prefix('\u{feff}') {
+        Some(input) => {
+            fixes.push(Fix::ByteOrderMark);
+            input
+        }
+        None => input,
+    };
+    let mut input = if input.contains("\r\n") {
+        fixes.push(Fix::CrLf);
+        input.replace("\r\n", "\n")
+    } else {
+        input.to_string()
+    };
+    let content_len = input.trim_end().len();
+    if !matches!(&input[content_len..], "" | "\n") {
+        fixes.push(Fix::TrailingWhitespace);
+    }
+    input.truncate(content_len);
+    if !input.is_empty() {
+        input.push('\n');
+    }
+    (input, fixes)
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing {
+        year: i32,
+        day: i32,
+        path: PathBuf,
+    },
+    /// The day's path is a directory of inputs rather than a single one.
+    Directory {
+        year: i32,
+        day: i32,
+        path: PathBuf,
+    },
+    Unreadable {
+        year: i32,
+        day: i32,
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { year, day, path } => write!(
+                f,
+                "No input for {} day {} - expected a file at {}. Save your puzzle input there.",
+                year,
+                day,
+                path.display()
+            ),
+            InputError::Directory { year, day, path } => write!(
+                f,
+                "{} holds several inputs for {} day {}. Use --every-input to run them all, or \
+                 --input <FILE> to pick one.",
+                path.display(),
+                year,
+                day
+            ),
+            InputError::Unreadable {
+                year,
+                day,
+                path,
+                source,
+            } => write!(
+                f,
+                "Couldn't read input for {} day {} from {}: {}",
+                year,
+                day,
+                path.display(),
+                source
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Missing { .. } | InputError::Directory { .. } => None,
+            InputError::Unreadable { source, .. } => Some(source),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_input_path_is_year_and_day_number() {
+        let dir = InputDir::new("/some/where");
+        assert_eq!(dir.path_for(2023, 7), PathBuf::from("/some/where/2023/7"));
+        assert_eq!(
+            dir.answers_path(2023),
+            PathBuf::from("/some/where/2023/answers.toml")
+        );
+    }
+
+    #[test]
+    fn check_input_source_from_arg() {
+        assert_eq!(InputSource::from_arg(Path::new("-")), InputSource::Stdin);
+        assert_eq!(
+            InputSource::from_arg(Path::new("edge_case.txt")).to_string(),
+            "edge_case.txt"
+        );
+    }
+
+    #[test]
+    fn check_missing_input_is_reported() {
+        let dir = InputDir::new("/definitely/not/a/real/input/dir");
+        match dir.load(2023, 3) {
+            Err(InputError::Missing { year, day, path }) => {
+                assert_eq!((year, day), (2023, 3));
+                assert_eq!(
+                    path,
+                    PathBuf::from("/definitely/not/a/real/input/dir/2023/3")
+                );
+            }
+            other => panic!("Expected missing input error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn check_every_input_in_a_directory() {
+        let root = std::env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        let day_dir = root.join("2023").join("3");
+        fs::create_dir_all(&day_dir).unwrap();
+        for file in ["bob.txt", "alice.txt", "notes.md"] {
+            fs::write(day_dir.join(file), "...").unwrap();
+        }
+        fs::write(root.join("2023").join("4"), "...").unwrap();
+        let dir = InputDir::new(&root);
+        let inputs = dir.inputs_for(2023, 3);
+        let single = dir.inputs_for(2023, 4);
+        let loaded = dir.load(2023, 3);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            inputs.unwrap(),
+            vec![day_dir.join("alice.txt"), day_dir.join("bob.txt")]
+        );
+        assert_eq!(single.unwrap(), vec![root.join("2023").join("4")]);
+        assert!(matches!(loaded, Err(InputError::Directory { .. })));
+        assert!(matches!(
+            dir.inputs_for(2023, 5),
+            Err(InputError::Missing { .. })
+        ));
+    }
+
+    #[test]
+    fn check_inputs_are_normalised() {
+        assert_eq!(
+            normalise("1, 2\n4, 3\n"),
+            ("1, 2\n4, 3\n".to_string(), vec![])
+        );
+        assert_eq!(
+            normalise("1, 2\n4, 3"),
+            ("1, 2\n4, 3\n".to_string(), vec![])
+        );
+        assert_eq!(
+            normalise("\u{feff}seeds: 1\r\n\r\nmap:\r\n1 2 3\r\n\r\n"),
+            (
+                "seeds: 1\n\nmap:\n1 2 3\n".to_string(),
+                vec![Fix::ByteOrderMark, Fix::CrLf, Fix::TrailingWhitespace]
+            )
+        );
+        assert_eq!(
+            normalise("    [D] \n[N] [C] \n \t\n"),
+            (
+                "    [D] \n[N] [C]\n".to_string(),
+                vec![Fix::TrailingWhitespace]
+            )
+        );
+        assert_eq!(normalise(""), ("".to_string(), vec![]));
+    }
+}
@@ -0,0 +1,323 @@
+//! Sets of integer ranges, and maps that shift ranges of keys around, for puzzles where there are
+//! far too many values to handle one at a time. Ranges are half-open, like `start..end`.
+
+use std::ops::Range;
+
+use num::PrimInt;
+
+/// A set of integers, stored as sorted ranges that don't overlap or touch.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: vec![] }
+    }
+
+    /// The ranges in the set, in order.
+    pub fn ranges(&self) -> &[Range<T>] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// How many values are in the set.
+    pub fn len(&self) -> T {
+        self.ranges
+            .iter()
+            .fold(T::zero(), |len, range| len + (range.end - range.start))
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let idx = self.ranges.partition_point(|range| range.end <= value);
+        self.ranges
+            .get(idx)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        *self = self.union(&IntervalSet::from_iter([range]));
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges
+            .iter()
+            .chain(other.ranges.iter())
+            .cloned()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let start = x.start.max(y.start);
+            let end = x.end.min(y.end);
+            if start < end {
+                ranges.push(start..end);
+            }
+            // Whichever finishes first can't overlap anything else in the other set
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        IntervalSet { ranges }
+    }
+
+    /// Everything in this set that isn't in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut ranges = vec![];
+        let mut others = other.ranges.iter().peekable();
+        for range in &self.ranges {
+            let mut start = range.start;
+            while let Some(hole) = others.peek() {
+                if hole.end <= start {
+                    others.next();
+                    continue;
+                }
+                if hole.start >= range.end {
+                    break;
+                }
+                if start < hole.start {
+                    ranges.push(start..hole.start);
+                }
+                start = hole.end;
+                if start >= range.end {
+                    break;
+                }
+                others.next();
+            }
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+/// Sorts and merges the ranges, ignoring any that are empty.
+impl<T: PrimInt> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut sorted = iter
+            .into_iter()
+            .filter(|range| range.start < range.end)
+            .collect::<Vec<_>>();
+        sorted.sort_by_key(|range| range.start);
+        let mut ranges: Vec<Range<T>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => ranges.push(range),
+            }
+        }
+        IntervalSet { ranges }
+    }
+}
+
+/// A function on integers that shifts some ranges of keys to somewhere else, leaving every key
+/// outside them as it is, like the almanac maps in 2023 day 5.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeMap<T> {
+    /// Each source range and where its start maps to, sorted by source and not overlapping.
+    entries: Vec<(Range<T>, T)>,
+}
+
+impl<T: PrimInt> RangeMap<T> {
+    /// Build a map from `(destination, source, length)` triples, the way puzzles tend to list
+    /// them. The source ranges mustn't overlap.
+    pub fn new(triples: impl IntoIterator<Item = (T, T, T)>) -> Self {
+        Self::from_entries(
+            triples
+                .into_iter()
+                .map(|(destination, source, length)| (source..source + length, destination))
+                .collect(),
+        )
+    }
+
+    fn from_entries(mut entries: Vec<(Range<T>, T)>) -> Self {
+        entries.retain(|(source, _)| source.start < source.end);
+        entries.sort_by_key(|(source, _)| source.start);
+        RangeMap { entries }
+    }
+
+    /// Where `key` maps to.
+    pub fn get(&self, key: &T) -> T {
+        let idx = self
+            .entries
+            .partition_point(|(source, _)| source.end <= *key);
+        match self.entries.get(idx) {
+            Some((source, destination)) if source.start <= *key => {
+                *destination + (*key - source.start)
+            }
+            _ => *key,
+        }
+    }
+
+    /// Split `range` into the pieces that map to different places, each with where its start
+    /// maps to. Pieces outside every source range map to themselves.
+    fn pieces(&self, range: Range<T>) -> Vec<(Range<T>, T)> {
+        let mut pieces = vec![];
+        let mut start = range.start;
+        let first = self
+            .entries
+            .partition_point(|(source, _)| source.end <= start);
+        for (source, destination) in &self.entries[first..] {
+            if source.start >= range.end {
+                break;
+            }
+            if start < source.start {
+                pieces.push((start..source.start, start));
+                start = source.start;
+            }
+            let end = source.end.min(range.end);
+            pieces.push((start..end, *destination + (start - source.start)));
+            start = end;
+        }
+        if start < range.end {
+            pieces.push((start..range.end, start));
+        }
+        pieces
+    }
+
+    /// Where every key in `range` maps to, split up into as many ranges as it takes.
+    pub fn map_range(&self, range: Range<T>) -> Vec<Range<T>> {
+        self.pieces(range)
+            .into_iter()
+            .map(|(piece, destination)| shift(&piece, piece.start, destination))
+            .collect()
+    }
+
+    /// Where every key in `set` maps to.
+    pub fn map(&self, set: &IntervalSet<T>) -> IntervalSet<T> {
+        set.ranges()
+            .iter()
+            .flat_map(|range| self.map_range(range.clone()))
+            .collect()
+    }
+
+    /// A single map that does this one and then `next`.
+    pub fn compose(&self, next: &RangeMap<T>) -> RangeMap<T> {
+        let mut entries = vec![];
+        for (piece, destination) in self.pieces(T::min_value()..T::max_value()) {
+            let image = shift(&piece, piece.start, destination);
+            for (next_piece, next_destination) in next.pieces(image) {
+                let source = shift(&next_piece, destination, piece.start);
+                // Keys that end up back where they started don't need an entry
+                if source.start != next_destination {
+                    entries.push((source, next_destination));
+                }
+            }
+        }
+        RangeMap::from_entries(entries)
+    }
+
+    /// The map that undoes this one, sending each destination range back to its source. This
+    /// only makes sense if no two keys map to the same place, e.g. a range isn't shifted onto
+    /// keys that are left where they are.
+    pub fn inverse(&self) -> RangeMap<T> {
+        RangeMap::from_entries(
+            self.entries
+                .iter()
+                .map(|(source, destination)| {
+                    (shift(source, source.start, *destination), source.start)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Move `range` so that `from` would land on `to`. Ranges that stay put are left alone, so the
+/// identity pieces spanning the whole domain never have their lengths worked out and overflow.
+fn shift<T: PrimInt>(range: &Range<T>, from: T, to: T) -> Range<T> {
+    if from == to {
+        range.clone()
+    } else {
+        to + (range.start - from)..to + (range.end - from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(ranges: &[Range<i64>]) -> IntervalSet<i64> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn check_sets_merge() {
+        let mut merged = set(&[5..8, 0..2, 1..3, 8..10, 4..4]);
+        assert_eq!(merged.ranges(), [0..3, 5..10]);
+        assert_eq!(
+            (merged.len(), merged.min(), merged.max()),
+            (8, Some(0), Some(9))
+        );
+        assert!(merged.contains(9) && !merged.contains(3) && !merged.contains(10));
+        merged.insert(-4..0);
+        assert_eq!(merged.ranges(), [-4..3, 5..10]);
+    }
+
+    #[test]
+    fn check_set_operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        let union = a.union(&b);
+        assert_eq!(
+            (union.min(), union.max(), union.ranges().len()),
+            (Some(0), Some(39), 1)
+        );
+        assert_eq!(a.intersection(&b).ranges(), [5..10, 20..25, 28..30]);
+        assert_eq!(a.difference(&b).ranges(), [0..5, 25..28]);
+        assert_eq!(b.difference(&a).ranges(), [10..20, 30..40]);
+        assert_eq!(
+            a.difference(&set(&[2..4, 6..8])).ranges(),
+            [0..2, 4..6, 8..10, 20..30]
+        );
+        assert!(a.intersection(&IntervalSet::new()).is_empty());
+    }
+
+    #[test]
+    fn check_ranges_are_split() {
+        // 50..98 shifts up by 2, 98..100 wraps down to 50
+        let map = RangeMap::new([(52, 50, 48), (50, 98, 2)]);
+        assert_eq!(map.get(&79), 81);
+        assert_eq!(map.get(&99), 51);
+        assert_eq!(map.get(&10), 10);
+        assert_eq!(map.map_range(40..105), [40..50, 52..100, 50..52, 100..105]);
+        // Everything in 40..105 still ends up somewhere in 40..105
+        let keys = set(&[40..60, 60..105]);
+        assert_eq!(map.map(&keys), keys);
+    }
+
+    #[test]
+    fn check_compose_and_inverse() {
+        let first = RangeMap::new([(52, 50, 48), (50, 98, 2)]);
+        let second = RangeMap::new([(0, 15, 37), (37, 52, 2), (39, 0, 15)]);
+        let both = first.compose(&second);
+        for key in 0..120 {
+            assert_eq!(both.get(&key), second.get(&first.get(&key)), "{}", key);
+        }
+        let inverse = both.inverse();
+        for key in 0..120 {
+            assert_eq!(inverse.get(&both.get(&key)), key);
+        }
+        assert_eq!(RangeMap::default().compose(&first), first);
+    }
+}
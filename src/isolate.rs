@@ -0,0 +1,263 @@
+//! Running a day in a child process, so it can be held to a memory limit.
+//!
+//! The counting allocator can't unwind when a day goes over its budget, it can only refuse the
+//! allocation, which aborts the whole process (see [`alloc_stats::limit_memory`]). So a day with
+//! a memory limit is solved by a copy of the runner started with the hidden `run-day` command.
+//! That reads the day to solve and its input from stdin, and reports its progress back on stderr.
+//! If it aborts, the phase it was in is reported as `OUT OF MEMORY`, and everything the day had
+//! going, threads included, goes with it.
+
+use std::{
+    env, fmt,
+    io::{self, BufRead, BufReader, Read, Write},
+    process::{Command, Stdio},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    alloc_stats,
+    error::{AocError, Part},
+    registry, DayResult, Limits, Parts, TimedAnswers,
+};
+
+/// Marks the lines of the child's stderr that are meant for the runner rather than the user.
+const MESSAGE_PREFIX: &str = "@@run-day ";
+
+/// What the child should solve, sent on the first line of its stdin ahead of the input.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+struct Job {
+    year: i32,
+    day: i32,
+    parts: Parts,
+    limits: Limits,
+}
+
+/// Sent by the child to tell the runner how it's getting on.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+enum Message {
+    /// A phase is starting, with the answers from the ones before it.
+    Starting(Part, TimedAnswers),
+    /// The day is done, whether or not it succeeded.
+    Finished(DayResult),
+}
+
+/// Solve the selected parts of a day in a child process held to `limits.memory`.
+/// Fails the day if the child can't be started.
+pub fn run_in_child(
+    year: i32,
+    day: i32,
+    input_lines: &str,
+    parts: Parts,
+    limits: Limits,
+) -> DayResult {
+    let job = Job {
+        year,
+        day,
+        parts,
+        limits,
+    };
+    watch_child(job, input_lines).unwrap_or_else(|err| {
+        DayResult::failed(
+            day,
+            AocError::new(format!("Couldn't run in a child process: {}", err)).in_day(day),
+        )
+    })
+}
+
+fn watch_child(job: Job, input_lines: &str) -> io::Result<DayResult> {
+    let mut child = Command::new(env::current_exe()?)
+        .arg("run-day")
+        .stdin(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // The child reads all of this before writing anything, so it can't block on stderr. If
+        // it stops early anyway, its exit status says why.
+        let _ = writeln!(stdin, "{}", serde_json::to_string(&job)?)
+            .and_then(|()| stdin.write_all(input_lines.as_bytes()));
+    }
+    let mut progress = Progress::new(job);
+    if let Some(stderr) = child.stderr.take() {
+        for line in BufReader::new(stderr).lines() {
+            if let Some(line) = progress.read(&line?) {
+                eprintln!("{}", line);
+            }
+        }
+    }
+    let status = child.wait()?;
+    Ok(progress.into_result(&status))
+}
+
+/// What the runner has heard from the child so far.
+struct Progress {
+    job: Job,
+    /// The phase that was last started, and the answers from before it.
+    running: Part,
+    answers: TimedAnswers,
+    finished: Option<DayResult>,
+    over_budget: bool,
+}
+
+impl Progress {
+    fn new(job: Job) -> Self {
+        Progress {
+            job,
+            running: Part::Parse,
+            answers: TimedAnswers::default(),
+            finished: None,
+            over_budget: false,
+        }
+    }
+
+    /// Take in a line the child wrote to stderr, handing it back if it's meant for the user.
+    fn read<'a>(&mut self, line: &'a str) -> Option<&'a str> {
+        if line == alloc_stats::OVER_BUDGET.trim_end() {
+            self.over_budget = true;
+            return None;
+        }
+        let Some(message) = line.strip_prefix(MESSAGE_PREFIX) else {
+            // Anything after going over is the abort complaining about the refused allocation
+            return (!self.over_budget).then_some(line);
+        };
+        match serde_json::from_str(message) {
+            Ok(Message::Starting(part, answers)) => {
+                self.running = part;
+                self.answers = answers;
+            }
+            Ok(Message::Finished(result)) => self.finished = Some(result),
+            Err(_) => return Some(line),
+        }
+        None
+    }
+
+    /// The day's result, once the child has exited with `status`.
+    fn into_result(self, status: &dyn fmt::Display) -> DayResult {
+        if let Some(result) = self.finished {
+            return result;
+        }
+        let message = match self.job.limits.memory {
+            Some(limit) if self.over_budget => format!(
+                "OUT OF MEMORY, used more than {}",
+                alloc_stats::format_bytes(limit)
+            ),
+            _ => format!("Stopped unexpectedly ({})", status),
+        };
+        DayResult {
+            day: self.job.day,
+            answers: self.answers,
+            error: Some(AocError::new(message).during(self.job.day, self.running)),
+        }
+    }
+}
+
+/// The child's side of [`run_in_child`]: solve the day described on stdin, reporting back on
+/// stderr.
+pub fn run_day_from_stdin() -> io::Result<()> {
+    let mut stdin = String::new();
+    io::stdin().read_to_string(&mut stdin)?;
+    let (job, input_lines) = stdin.split_once('\n').unwrap_or((&stdin, ""));
+    let job: Job = serde_json::from_str(job)?;
+    let result = match registry::get(job.year, job.day) {
+        Some(solution) => {
+            let _budget = alloc_stats::limit_memory(job.limits.memory);
+            let mut report_start = |part, answers: &TimedAnswers| {
+                send(&Message::Starting(part, answers.clone()));
+            };
+            solution.run_watched(input_lines, job.parts, job.limits, &mut report_start)
+        }
+        None => DayResult::failed(
+            job.day,
+            AocError::new(format!(
+                "There's no solution for {} day {}",
+                job.year, job.day
+            ))
+            .in_day(job.day),
+        ),
+    };
+    send(&Message::Finished(result));
+    Ok(())
+}
+
+fn send(message: &Message) {
+    let message = serde_json::to_string(message).expect("Messages are always valid JSON");
+    eprintln!("{}{}", MESSAGE_PREFIX, message);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::Timings;
+
+    fn job() -> Job {
+        Job {
+            year: 2023,
+            day: 5,
+            parts: Parts::Both,
+            limits: Limits {
+                memory: Some(64 * 1024 * 1024),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn message_line(message: &Message) -> String {
+        format!(
+            "{}{}",
+            MESSAGE_PREFIX,
+            serde_json::to_string(message).unwrap()
+        )
+    }
+
+    #[test]
+    fn check_child_over_budget() {
+        let answers = TimedAnswers {
+            part_one: Some("35".to_string()),
+            timings: Timings {
+                parse: Duration::from_micros(40),
+                part_one: Duration::from_micros(900),
+                part_two: Duration::ZERO,
+            },
+            ..Default::default()
+        };
+        let mut progress = Progress::new(job());
+        assert_eq!(
+            progress.read("Warning: tidied up stdin"),
+            Some("Warning: tidied up stdin")
+        );
+        let starting = message_line(&Message::Starting(Part::Two, answers.clone()));
+        assert_eq!(progress.read(&starting), None);
+        assert_eq!(progress.read(alloc_stats::OVER_BUDGET.trim_end()), None);
+        assert_eq!(
+            progress.read("memory allocation of 1024 bytes failed"),
+            None
+        );
+
+        let result = progress.into_result(&"signal: 6 (SIGABRT)");
+        assert_eq!(result.answers, answers);
+        assert!(result.completed(Part::One) && !result.completed(Part::Two));
+        assert_eq!(
+            result.error.unwrap().to_string(),
+            "day 5, part 2: OUT OF MEMORY, used more than 64.0 MiB"
+        );
+    }
+
+    #[test]
+    fn check_child_finished_or_crashed() {
+        let mut progress = Progress::new(job());
+        let finished = DayResult::failed(5, AocError::new("Bad seeds").during(5, Part::Parse));
+        assert_eq!(
+            progress.read(&message_line(&Message::Finished(finished.clone()))),
+            None
+        );
+        assert_eq!(progress.into_result(&"exit status: 0"), finished);
+
+        let crashed = Progress::new(job()).into_result(&"signal: 11 (SIGSEGV)");
+        assert_eq!(
+            crashed.error.unwrap().to_string(),
+            "day 5, parsing: Stopped unexpectedly (signal: 11 (SIGSEGV))"
+        );
+    }
+}
@@ -1,120 +1,583 @@
-use std::process::Command;
-
-pub mod day00;
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
-pub mod day17;
-pub mod day18;
-pub mod day19;
-pub mod day20;
-pub mod day21;
-pub mod day22;
-pub mod day23;
-pub mod day24;
-pub mod day25;
+use std::{
+    process::Command,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use alloc_stats::{AllocStats, PhaseAllocs};
+use bench::{BenchConfig, BenchResult};
+use clap::ValueEnum;
+use error::{catch_panic, panic_payload, AocError, AocResult, Part};
+use iai_report::{parse_iai_output, IaiStats};
+use serde::{Deserialize, Serialize};
+
+pub mod alloc_stats;
+pub mod answers;
+pub mod bench;
+pub mod console;
+pub mod error;
+pub mod grid;
+pub mod grid_graph;
+pub mod history;
+pub mod iai_report;
+pub mod input;
+pub mod interval;
+pub mod isolate;
+pub mod parser;
+pub mod point;
+pub mod registry;
+pub mod report;
+pub mod scaffold;
+pub mod selection;
+pub mod y2023;
 
 pub trait Solution {
-    type ParsedInput;
+    /// The day of the event this solves, used to find the input and to register the solution.
+    const DAY: i32;
+    /// The puzzle's title, as shown on the Advent of Code website.
+    const TITLE: &'static str;
+    /// Passed between threads when running with a time limit, so it can't borrow from anything.
+    /// It's cloned to check part two doesn't rely on part one, see [`Solution::try_solve`].
+    type ParsedInput: Clone + Send + 'static;
     /// Parse the input into the type used by the solution.
     /// You may wish to parse as you go rather than ahead of your part_one and part_two functions.
     /// If so, just return input_lines in your implementation of parse_input and do the parsing later.
-    fn parse_input(input_lines: &str) -> Self::ParsedInput;
-    fn part_one(parsed_input: &mut Self::ParsedInput) -> String;
-    fn part_two(parsed_input: &mut Self::ParsedInput) -> String;
+    /// Return an [`AocError`](error::AocError) rather than panicking if the input doesn't look as expected, the runner will
+    /// report it and move on to the next day.
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput>;
+    /// Solve part one. It's free to change the parsed input (e.g. sorting it in place), but
+    /// whatever it leaves behind is what part two gets when both parts are run.
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String>;
+    /// Solve part two. This must give the same answer whether or not part one has already run on
+    /// the parsed input, as only part two runs with `--part 2`. So if part one changes something
+    /// part two uses, part two has to set it up again itself.
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String>;
+    /// Parse and solve part one, panicking on failure. Handy for tests and benchmarks.
     fn solve_part_one(input_lines: &str) -> String {
-        Self::part_one(&mut Self::parse_input(input_lines))
+        Self::try_solve_part_one(input_lines).unwrap_or_else(|err| panic!("{}", err))
     }
+    /// Parse and solve part two, panicking on failure. Handy for tests and benchmarks.
     fn solve_part_two(input_lines: &str) -> String {
-        Self::part_two(&mut Self::parse_input(input_lines))
-    }
-    /// Solve the problem and print the solutions to stdout, optionally include wall-clock execution time for this run.
-    fn solve(input_lines: &str, include_time: bool) -> (String, String) {
-        if include_time {
-            Self::solve_with_time(input_lines)
-        } else {
-            let mut input = Self::parse_input(input_lines);
-            let p1 = Self::part_one(&mut input);
-            let p2 = Self::part_two(&mut input);
-            println!("----------");
-            println!("Part 1: {}\nPart 2: {}", p1, p2);
-            (p1, p2)
-        }
-    }
-    fn solve_with_time(input_lines: &str) -> (String, String) {
-        let start_time = std::time::Instant::now();
-        let mut input = Self::parse_input(input_lines);
-        let parse_time = start_time.elapsed().as_micros();
-        let start_time = std::time::Instant::now();
-        let p1 = Self::part_one(&mut input);
-        let p1_time = start_time.elapsed().as_micros();
-        let start_time = std::time::Instant::now();
-        let p2 = Self::part_two(&mut input);
-        let p2_time = start_time.elapsed().as_micros();
-        println!("----------");
-        println!("Parsing... ({} μs)", parse_time);
-        println!("Part 1: {} ({} μs)", p1, p1_time);
-        println!("Part 2: {} ({} μs)", p2, p2_time);
-        (p1, p2)
-    }
-}
-
-pub fn solve_day(day: &i32, include_time: bool) {
-    match day {
-        0 => day00::Day00::solve(include_str!("../inputs/0"), include_time),
-        1 => day01::Day01::solve(include_str!("../inputs/1"), include_time),
-        2 => day02::Day02::solve(include_str!("../inputs/2"), include_time),
-        3 => day03::Day03::solve(include_str!("../inputs/3"), include_time),
-        4 => day04::Day04::solve(include_str!("../inputs/4"), include_time),
-        5 => day05::Day05::solve(include_str!("../inputs/5"), include_time),
-        6 => day06::Day06::solve(include_str!("../inputs/6"), include_time),
-        7 => day07::Day07::solve(include_str!("../inputs/7"), include_time),
-        8 => day08::Day08::solve(include_str!("../inputs/8"), include_time),
-        9 => day09::Day09::solve(include_str!("../inputs/9"), include_time),
-        10 => day10::Day10::solve(include_str!("../inputs/10"), include_time),
-        11 => day11::Day11::solve(include_str!("../inputs/11"), include_time),
-        12 => day12::Day12::solve(include_str!("../inputs/12"), include_time),
-        13 => day13::Day13::solve(include_str!("../inputs/13"), include_time),
-        14 => day14::Day14::solve(include_str!("../inputs/14"), include_time),
-        15 => day15::Day15::solve(include_str!("../inputs/15"), include_time),
-        16 => day16::Day16::solve(include_str!("../inputs/16"), include_time),
-        17 => day17::Day17::solve(include_str!("../inputs/17"), include_time),
-        18 => day18::Day18::solve(include_str!("../inputs/18"), include_time),
-        19 => day19::Day19::solve(include_str!("../inputs/19"), include_time),
-        20 => day20::Day20::solve(include_str!("../inputs/20"), include_time),
-        21 => day21::Day21::solve(include_str!("../inputs/21"), include_time),
-        22 => day22::Day22::solve(include_str!("../inputs/22"), include_time),
-        23 => day23::Day23::solve(include_str!("../inputs/23"), include_time),
-        24 => day24::Day24::solve(include_str!("../inputs/24"), include_time),
-        25 => day25::Day25::solve(include_str!("../inputs/25"), include_time),
-        _ => panic!("Day not found"),
+        Self::try_solve_part_two(input_lines).unwrap_or_else(|err| panic!("{}", err))
+    }
+    fn try_solve_part_one(input_lines: &str) -> AocResult<String> {
+        let mut input =
+            Self::parse_input(input_lines).map_err(|err| err.during(Self::DAY, Part::Parse))?;
+        Self::part_one(&mut input).map_err(|err| err.during(Self::DAY, Part::One))
+    }
+    fn try_solve_part_two(input_lines: &str) -> AocResult<String> {
+        let mut input =
+            Self::parse_input(input_lines).map_err(|err| err.during(Self::DAY, Part::Parse))?;
+        Self::part_two(&mut input).map_err(|err| err.during(Self::DAY, Part::Two))
+    }
+    /// Parse and solve both parts, panicking on failure. Handy for tests.
+    fn solve(input_lines: &str) -> (String, String) {
+        Self::try_solve(input_lines).unwrap_or_else(|err| panic!("{}", err))
+    }
+    /// Parse the input once and solve both parts from it, as the runner does.
+    /// In debug builds (so in tests) part two is also solved from a fresh copy of the parsed
+    /// input, and it's an error if that gives a different answer, see [`Solution::part_two`].
+    fn try_solve(input_lines: &str) -> AocResult<(String, String)> {
+        let mut input =
+            Self::parse_input(input_lines).map_err(|err| err.during(Self::DAY, Part::Parse))?;
+        let untouched = cfg!(debug_assertions).then(|| input.clone());
+        let p1 = Self::part_one(&mut input).map_err(|err| err.during(Self::DAY, Part::One))?;
+        let p2 = Self::part_two(&mut input).map_err(|err| err.during(Self::DAY, Part::Two))?;
+        if let Some(mut untouched) = untouched {
+            let alone =
+                Self::part_two(&mut untouched).map_err(|err| err.during(Self::DAY, Part::Two))?;
+            if alone != p2 {
+                return Err(AocError::new(format!(
+                    "Answer depends on whether part one ran first: {} after part one, {} without. \
+                     Part two needs to undo or redo whatever part one changed in the input",
+                    p2, alone
+                ))
+                .during(Self::DAY, Part::Two));
+            }
+        }
+        Ok((p1, p2))
+    }
+    /// Solve the selected parts, recording how long parsing and each part took, and holding each
+    /// phase to the given [`Limits`]. Nothing is printed, see [`console`] for that.
+    /// When built with the `alloc-stats` feature, heap usage for each phase is recorded too.
+    /// Parts that aren't run have no answer and take no time. A phase that panics is reported as
+    /// an error like any other, rather than stopping the run.
+    ///
+    /// With a time limit, each phase runs on a worker thread and one that overruns is reported as
+    /// a `TIMEOUT` error. The phase can't be stopped, so it carries on in the background until it
+    /// finishes or the program exits, and days solved meanwhile are marked as
+    /// [`skewed`](TimedAnswers::skewed).
+    /// The memory limit isn't enforced here, see [`isolate`] for that.
+    fn solve_timed(input_lines: &str, parts: Parts, limits: Limits) -> DayResult {
+        Self::solve_watched(input_lines, parts, limits, &mut |_, _| ())
+    }
+    /// As [`Solution::solve_timed`], calling `watch` with the answers so far as each phase starts.
+    fn solve_watched(
+        input_lines: &str,
+        parts: Parts,
+        limits: Limits,
+        watch: &mut dyn FnMut(Part, &TimedAnswers),
+    ) -> DayResult {
+        let mut answers = TimedAnswers {
+            allocations: alloc_stats::enabled().then(PhaseAllocs::default),
+            skewed: RUNNING_WORKERS.load(Ordering::SeqCst) > 0,
+            ..Default::default()
+        };
+        let error = solve_phases::<Self>(&mut answers, input_lines, parts, limits, watch).err();
+        DayResult {
+            day: Self::DAY,
+            answers,
+            error,
+        }
+    }
+}
+
+/// Run the phases of a day in turn, filling in `answers` until one of them fails.
+fn solve_phases<S: Solution + ?Sized>(
+    answers: &mut TimedAnswers,
+    input_lines: &str,
+    parts: Parts,
+    limits: Limits,
+    watch: &mut dyn FnMut(Part, &TimedAnswers),
+) -> AocResult<()> {
+    let stopped = |part, stopped| {
+        let message = match stopped {
+            Stopped::TimedOut(limit) => format!("TIMEOUT after {}", report::format_duration(limit)),
+            Stopped::Crashed(message) => message,
+        };
+        AocError::new(message).during(S::DAY, part)
     };
+    let input_lines = input_lines.to_string();
+    watch(Part::Parse, answers);
+    let ((input, allocs), time) = run_within(S::DAY, Part::Parse, limits.time, move || {
+        measure_phase(|| S::parse_input(&input_lines))
+    })
+    .map_err(|reason| stopped(Part::Parse, reason))?;
+    let mut input = input.map_err(|err| err.during(S::DAY, Part::Parse))?;
+    answers.record(Part::Parse, time, allocs);
+
+    let solvers = [
+        (Part::One, S::part_one as fn(&mut _) -> _),
+        (Part::Two, S::part_two),
+    ];
+    for (part, solve) in solvers {
+        if !parts.includes(part) {
+            continue;
+        }
+        watch(part, answers);
+        let ((answer, allocs), time, returned) = run_within(S::DAY, part, limits.time, move || {
+            let (measured, time) = measure_phase(|| solve(&mut input));
+            (measured, time, input)
+        })
+        .map_err(|reason| stopped(part, reason))?;
+        input = returned;
+        let answer = answer.map_err(|err| err.during(S::DAY, part))?;
+        match part {
+            Part::One => answers.part_one = Some(answer),
+            _ => answers.part_two = Some(answer),
+        }
+        answers.record(part, time, allocs);
+    }
+    Ok(())
+}
+
+/// Run a phase of a solution, measuring its heap usage and how long it took.
+fn measure_phase<R>(phase: impl FnOnce() -> R) -> ((R, Option<AllocStats>), Duration) {
+    let start_time = Instant::now();
+    let measured = alloc_stats::measure(phase);
+    (measured, start_time.elapsed())
+}
+
+/// Worker threads still running a phase. Days are solved one at a time, so any still going when a
+/// day starts are phases that overran their time limit.
+static RUNNING_WORKERS: AtomicUsize = AtomicUsize::new(0);
+
+/// Counts a worker as running until it's dropped, even if the phase panics.
+struct RunningWorker;
+
+impl RunningWorker {
+    fn start() -> Self {
+        RUNNING_WORKERS.fetch_add(1, Ordering::SeqCst);
+        RunningWorker
+    }
+}
+
+impl Drop for RunningWorker {
+    fn drop(&mut self) {
+        RUNNING_WORKERS.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Why a phase didn't return anything.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Stopped {
+    /// It was given up on after taking this long.
+    TimedOut(Duration),
+    /// It panicked, with this message.
+    Crashed(String),
+}
+
+/// Run `phase` on a worker thread, giving up on it if it takes longer than `time_limit`.
+/// Without a limit it's run directly. Either way a panic in the phase is caught and returned.
+fn run_within<R: Send + 'static>(
+    day: i32,
+    part: Part,
+    time_limit: Option<Duration>,
+    phase: impl FnOnce() -> R + Send + 'static,
+) -> Result<R, Stopped> {
+    let Some(time_limit) = time_limit else {
+        return catch_panic(phase).map_err(Stopped::Crashed);
+    };
+    let (sender, receiver) = mpsc::channel();
+    let running = RunningWorker::start();
+    let worker = thread::Builder::new()
+        .name(format!("day {} {}", day, part))
+        .spawn(move || {
+            let result = catch_panic(phase);
+            // Stop counting before handing over, so the next day doesn't see this one as running
+            drop(running);
+            // Nobody is listening any more if the phase overran
+            let _ = sender.send(result);
+        })
+        .map_err(|err| Stopped::Crashed(format!("Couldn't start a worker thread: {}", err)))?;
+    match receiver.recv_timeout(time_limit) {
+        Ok(result) => result.map_err(Stopped::Crashed),
+        Err(RecvTimeoutError::Timeout) => Err(Stopped::TimedOut(time_limit)),
+        // The phase's own panics are caught on the worker, so this is one from handing over
+        Err(RecvTimeoutError::Disconnected) => Err(Stopped::Crashed(match worker.join() {
+            Err(panic) => format!("panicked: {}", panic_payload(panic.as_ref())),
+            Ok(()) => "The worker stopped without a result".to_string(),
+        })),
+    }
+}
+
+/// Limits on the resources each phase of a solution can use.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Limits {
+    /// Longest each phase can take before it's given up on as a `TIMEOUT`.
+    pub time: Option<Duration>,
+    /// Most heap memory the day can use, in bytes. Only enforced for a day run in a child
+    /// process, see [`isolate`].
+    pub memory: Option<usize>,
+}
+
+/// Which parts of a puzzle to run.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+pub enum Parts {
+    #[default]
+    Both,
+    #[value(name = "1")]
+    One,
+    #[value(name = "2")]
+    Two,
+}
+
+impl Parts {
+    /// Whether `part` should be run. Parsing is always needed.
+    pub fn includes(&self, part: Part) -> bool {
+        matches!(
+            (self, part),
+            (_, Part::Parse) | (Parts::Both, _) | (Parts::One, Part::One) | (Parts::Two, Part::Two)
+        )
+    }
+}
+
+/// Wall-clock time taken by each phase of a solution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part_one: Duration,
+    pub part_two: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part_one + self.part_two
+    }
+}
+
+/// The answers for a day, along with how long it took to get them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimedAnswers {
+    /// `None` if the part wasn't run.
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+    pub timings: Timings,
+    /// Heap usage of each phase, only recorded when built with the `alloc-stats` feature.
+    pub allocations: Option<PhaseAllocs>,
+    /// Whether a phase that timed out earlier was still running in the background, so the
+    /// timings and heap usage include whatever it was doing too.
+    pub skewed: bool,
+}
+
+impl TimedAnswers {
+    pub fn answer(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::Parse => None,
+            Part::One => self.part_one.as_deref(),
+            Part::Two => self.part_two.as_deref(),
+        }
+    }
+
+    /// Note how long a phase took and how much it allocated.
+    fn record(&mut self, part: Part, time: Duration, allocs: Option<AllocStats>) {
+        match part {
+            Part::Parse => self.timings.parse = time,
+            Part::One => self.timings.part_one = time,
+            Part::Two => self.timings.part_two = time,
+        }
+        if let (Some(allocations), Some(allocs)) = (&mut self.allocations, allocs) {
+            match part {
+                Part::Parse => allocations.parse = allocs,
+                Part::One => allocations.part_one = allocs,
+                Part::Two => allocations.part_two = allocs,
+            }
+        }
+    }
+}
+
+/// Everything that came out of running a day: the answers and timings of each phase that
+/// finished, and the error that stopped the rest, if any.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DayResult {
+    pub day: i32,
+    pub answers: TimedAnswers,
+    /// Phases from the one this came from onwards have no answers or timings.
+    pub error: Option<AocError>,
 }
 
-pub fn bench_day(day: &i32) {
-    println!("Benchmarking day {}...", day);
+impl DayResult {
+    /// A day that didn't get as far as running, e.g. because its input couldn't be read.
+    pub fn failed(day: i32, error: AocError) -> Self {
+        DayResult {
+            day,
+            answers: TimedAnswers::default(),
+            error: Some(error),
+        }
+    }
+
+    /// Whether `part` finished, rather than failing or not being reached.
+    pub fn completed(&self, part: Part) -> bool {
+        match part {
+            // Errors from before a part started, like unreadable input, count against parsing
+            Part::Parse => match &self.error {
+                Some(err) => matches!(err.part, Some(Part::One | Part::Two)),
+                None => true,
+            },
+            part => self.answers.answer(part).is_some(),
+        }
+    }
+
+    /// The answers, or the error if any phase failed.
+    pub fn into_result(self) -> AocResult<TimedAnswers> {
+        match self.error {
+            Some(err) => Err(err),
+            None => Ok(self.answers),
+        }
+    }
+}
+
+/// Object-safe companion to [`Solution`], so solutions for different days can be stored together
+/// and run without knowing their `ParsedInput` types.
+/// Every `Solution` gets this for free, see [`registry`] for the list of all of them.
+pub trait DynSolution: Sync {
+    fn day(&self) -> i32;
+    fn title(&self) -> &'static str;
+    /// Parse the input and solve the selected parts, as [`Solution::solve_timed`].
+    fn run(&self, input_lines: &str, parts: Parts, limits: Limits) -> DayResult;
+    /// As [`DynSolution::run`], calling `watch` as each phase starts, see
+    /// [`Solution::solve_watched`].
+    fn run_watched(
+        &self,
+        input_lines: &str,
+        parts: Parts,
+        limits: Limits,
+        watch: &mut dyn FnMut(Part, &TimedAnswers),
+    ) -> DayResult;
+    /// Benchmark each phase of the solution in-process, see [`bench::bench_solution`], reporting a
+    /// panic as an error too.
+    fn bench(&self, input_lines: &str, config: &BenchConfig) -> AocResult<BenchResult>;
+}
+
+impl<T: Solution + Sync> DynSolution for T {
+    fn day(&self) -> i32 {
+        T::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        T::TITLE
+    }
+
+    fn run(&self, input_lines: &str, parts: Parts, limits: Limits) -> DayResult {
+        T::solve_timed(input_lines, parts, limits)
+    }
+
+    fn run_watched(
+        &self,
+        input_lines: &str,
+        parts: Parts,
+        limits: Limits,
+        watch: &mut dyn FnMut(Part, &TimedAnswers),
+    ) -> DayResult {
+        T::solve_watched(input_lines, parts, limits, watch)
+    }
+
+    fn bench(&self, input_lines: &str, config: &BenchConfig) -> AocResult<BenchResult> {
+        catch_panic(|| bench::bench_solution::<T>(input_lines, config))
+            .unwrap_or_else(|message| Err(AocError::new(message).in_day(T::DAY)))
+    }
+}
+
+/// Solve both parts of the given day's puzzle for the supplied input.
+/// A day without a solution fails like one that can't read its input.
+pub fn solve_day(year: &i32, day: &i32, input_lines: &str) -> DayResult {
+    match registry::get(*year, *day) {
+        Some(solution) => solution.run(input_lines, Parts::Both, Limits::default()),
+        None => DayResult::failed(
+            *day,
+            AocError::new(format!("There's no solution for {} day {}", year, day)).in_day(*day),
+        ),
+    }
+}
+
+/// Run the iai benches for a day and collect the counts for each bench function.
+/// Returns iai's output as the error if it didn't produce any results, e.g. because valgrind
+/// isn't installed.
+pub fn bench_day(year: &i32, day: &i32) -> Result<Vec<(String, IaiStats)>, String> {
     let result = Command::new("cargo")
         .args([
             "bench",
             "--bench",
-            format!("day{:02}", day).as_str(),
+            format!("y{}_day{:02}", year, day).as_str(),
             "--quiet",
             "0",
         ])
         .output()
-        .expect("Failed to run benchmark");
-    println!("{}", String::from_utf8(result.stdout).unwrap());
+        .map_err(|err| format!("Failed to run benchmark: {}", err))?;
+    let stdout = String::from_utf8_lossy(&result.stdout);
+    let benches = parse_iai_output(&stdout);
+    if benches.is_empty() {
+        Err(format!(
+            "No results from iai for {} day {}:\n{}{}",
+            year,
+            day,
+            stdout,
+            String::from_utf8_lossy(&result.stderr)
+        ))
+    } else {
+        Ok(benches)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the lines of its input in part one, and never finishes part two.
+    struct Stuck;
+
+    impl Solution for Stuck {
+        const DAY: i32 = 8;
+        const TITLE: &'static str = "Stuck";
+        type ParsedInput = Vec<String>;
+
+        fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+            Ok(input_lines.lines().map(str::to_string).collect())
+        }
+
+        fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+            Ok(parsed_input.len().to_string())
+        }
+
+        fn part_two(_: &mut Self::ParsedInput) -> AocResult<String> {
+            std::thread::sleep(Duration::from_secs(60));
+            Ok("ZZZ".to_string())
+        }
+    }
+
+    /// Sorts the numbers in place in part one, which part two then relies on by mistake.
+    struct Sorted;
+
+    impl Solution for Sorted {
+        const DAY: i32 = 7;
+        const TITLE: &'static str = "Sorted";
+        type ParsedInput = Vec<u32>;
+
+        fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+            Ok(input_lines
+                .split(',')
+                .map(str::parse)
+                .collect::<Result<_, _>>()?)
+        }
+
+        fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+            parsed_input.sort();
+            Ok(parsed_input[0].to_string())
+        }
+
+        fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+            Ok(parsed_input[1].to_string())
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn check_part_two_cant_rely_on_part_one() {
+        assert_eq!(
+            Sorted::try_solve("3,1,2").unwrap_err().to_string(),
+            "day 7, part 2: Answer depends on whether part one ran first: 2 after part one, 1 \
+             without. Part two needs to undo or redo whatever part one changed in the input"
+        );
+        assert_eq!(Sorted::solve("1,2,3"), ("1".to_string(), "2".to_string()));
+    }
+
+    #[test]
+    fn check_parts_within_time_limit() {
+        let limit = Limits {
+            time: Some(Duration::from_millis(200)),
+            ..Default::default()
+        };
+        let answers = Stuck::solve_timed("AAA\nBBB", Parts::One, limit)
+            .into_result()
+            .unwrap();
+        assert_eq!(answers.part_one.as_deref(), Some("2"));
+        assert_eq!(answers.part_two, None);
+
+        let result = Stuck::solve_timed("AAA\nBBB", Parts::Both, limit);
+        assert_eq!(result.answers.part_one.as_deref(), Some("2"));
+        assert!(result.completed(Part::One) && !result.completed(Part::Two));
+        assert_eq!(
+            result.error.unwrap().to_string(),
+            "day 8, part 2: TIMEOUT after 200.00 ms"
+        );
+
+        // Part two is still sleeping in the background
+        let after = Stuck::solve_timed("AAA", Parts::One, Limits::default());
+        assert_eq!(after.answers.part_one.as_deref(), Some("1"));
+        assert!(after.answers.skewed);
+    }
+
+    #[test]
+    fn check_panics_become_errors() {
+        let limits = [
+            Limits::default(),
+            Limits {
+                time: Some(Duration::from_secs(10)),
+                ..Default::default()
+            },
+        ];
+        for limit in limits {
+            // Part two indexes past the only number
+            let result = Sorted::solve_timed("5", Parts::Both, limit);
+            assert_eq!(result.answers.part_one.as_deref(), Some("5"));
+            let err = result.error.unwrap();
+            assert_eq!((err.day, err.part), (Some(7), Some(Part::Two)));
+            assert!(err.message.starts_with("panicked at src/lib.rs:"));
+            assert!(err.message.contains("index out of bounds"));
+        }
+    }
 }
@@ -1,34 +1,581 @@
-use advent_of_code_template::{bench_day, solve_day};
-use clap::Parser;
+use std::{fs, path::Path, path::PathBuf, process::ExitCode, time::Duration};
+
+use advent_of_code_template::{
+    alloc_stats,
+    answers::{Answers, CheckReport},
+    bench::BenchConfig,
+    bench_day,
+    console::Reporter,
+    error::AocError,
+    history::{History, Recorder, HISTORY_FILE},
+    iai_report::IaiReport,
+    input::{self, Fix, InputDir, InputError, InputSource, DEFAULT_INPUT_DIR},
+    isolate, registry,
+    report::{ReportFormat, RunReport},
+    scaffold::NewDay,
+    selection::{DayList, DaySelection},
+    DayResult, DynSolution, Limits, Parts,
+};
+use clap::{Parser, Subcommand, ValueEnum};
+use rayon::prelude::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+enum BenchKind {
+    /// Count instructions and cache accesses using iai, requires valgrind.
+    Iai,
+    /// Repeatedly time each phase and report statistics on the wall-clock time taken.
+    Wall,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Compares the timings and instruction counts recorded at two commits, and fails if any
+    /// phase got worse by more than the threshold.
+    Compare {
+        /// Commit to compare against. Defaults to the commit measured before the candidate.
+        #[arg(long)]
+        baseline: Option<String>,
+
+        /// Commit to check for regressions. Defaults to the most recently measured commit.
+        #[arg(long)]
+        candidate: Option<String>,
+
+        /// Percentage increase that counts as a regression.
+        #[arg(long, default_value_t = 5.0)]
+        threshold: f64,
+    },
+
+    /// Generates the solution stub and bench for a new day, and registers them.
+    /// Refuses to touch a day that already exists.
+    NewDay {
+        /// Day to create.
+        #[arg(value_parser = clap::value_parser!(i32).range(1..=25))]
+        day: i32,
+
+        /// Event the puzzle is from. Its module is created if this is the first day of the year.
+        #[arg(long, default_value_t = registry::DEFAULT_YEAR)]
+        year: i32,
+
+        /// Title of the puzzle.
+        #[arg(long)]
+        title: Option<String>,
+    },
+
+    /// Solves the day described on stdin and reports back on stderr, for running a day with a
+    /// memory limit in a process of its own.
+    #[command(hide = true)]
+    RunDay,
+}
 
 #[derive(Parser)]
 #[command(author="Finlay Wojtan", version="0.1.0", about="Advent of Code test and benchmarking template", long_about = None)]
+#[command(args_conflicts_with_subcommands = true)]
 struct Cli {
-    /// Selects a single day to run. If not specified, all days are run.
-    day: Option<i32>,
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Selects the days to run: a single day (`5`), a range (`3..7` or `3..=7`), a list (`1,5,9`),
+    /// or `all` to run every implemented day. Defaults to `all`.
+    days: Option<DaySelection>,
+
+    /// Selects which year's solutions to run.
+    #[arg(short, long, default_value_t = registry::DEFAULT_YEAR)]
+    year: i32,
 
     /// Benchmarks the solution for given days.
-    #[arg(short, long)]
-    bench: bool,
+    /// Counts instructions with iai by default, or pass `--bench=wall` to time them in-process.
+    #[arg(short, long, value_enum, num_args = 0..=1, default_missing_value = "iai")]
+    bench: Option<BenchKind>,
+
+    /// Runs only part one or part two.
+    #[arg(
+        short,
+        long,
+        value_enum,
+        default_value = "both",
+        conflicts_with = "bench"
+    )]
+    part: Parts,
+
+    /// Solves this many days at once for a quick check of every answer, or one per core if no
+    /// number is given. Timings from a parallel run are skewed by the other days running alongside
+    /// them, so they aren't shown or recorded; leave this at 1 to time each day on its own.
+    #[arg(
+        short,
+        long,
+        num_args = 0..=1,
+        default_value_t = 1,
+        default_missing_value = "0",
+        conflicts_with_all = ["bench", "every_input"]
+    )]
+    jobs: usize,
+
+    /// Gives up on any phase of a solution that takes longer than this many seconds, reporting it
+    /// as a TIMEOUT and moving on to the next day.
+    #[arg(long, value_parser = parse_seconds, conflicts_with = "bench")]
+    timeout: Option<Duration>,
+
+    /// Gives up on any part of a solution that takes a day over this many MiB of heap memory,
+    /// reporting it as OUT OF MEMORY and moving on to the next day, rather than letting it take the
+    /// whole machine down. Each day runs in a process of its own so it can be stopped.
+    /// Needs the `alloc-stats` feature for the allocator that enforces it.
+    #[arg(long, value_name = "MIB", conflicts_with_all = ["bench", "jobs"])]
+    memory_limit: Option<usize>,
+
+    /// Maximum number of measurements to take for each day when running `--bench=wall`.
+    #[arg(long, default_value_t = BenchConfig::default().samples)]
+    samples: usize,
+
+    /// Compares the answers with those recorded in answers.toml in the input directory.
+    #[arg(short, long, conflicts_with = "bench")]
+    check: bool,
+
+    /// Writes a report of the answers and timings for every day that ran, or of the iai results
+    /// when benchmarking with iai.
+    #[arg(short, long, value_enum)]
+    format: Option<ReportFormat>,
+
+    /// File to write the report to. If not specified, it's printed after the answers.
+    #[arg(short, long, requires = "format")]
+    output: Option<PathBuf>,
+
+    /// Reads the input from this file instead of the input directory, or from stdin if it's `-`.
+    /// Only one day can be run with a custom input.
+    #[arg(short, long, conflicts_with = "check")]
+    input: Option<PathBuf>,
+
+    /// Runs each day over every input for it, to catch solutions that only work for one input.
+    /// Put the inputs in a directory per day, e.g. `inputs/2023/5/alice.txt`. Panics are reported
+    /// along with the answers rather than stopping the run.
+    #[arg(long, conflicts_with_all = ["check", "input", "bench"])]
+    every_input: bool,
+
+    /// Directory containing the puzzle inputs, in a directory per year with one file per day
+    /// named by the day number, e.g. `inputs/2023/5`.
+    #[arg(long, default_value = DEFAULT_INPUT_DIR)]
+    inputs: PathBuf,
 }
 
-fn main() {
+fn main() -> ExitCode {
     let cli = Cli::parse();
-    let mut days = (0..=25).collect::<Vec<i32>>();
-    let mut days_to_execute = vec![];
-    if let Some(day) = cli.day {
-        if !days.contains(&day) {
-            panic!("Day not found");
+    match cli.command {
+        Some(Command::Compare {
+            baseline,
+            candidate,
+            threshold,
+        }) => return compare(baseline, candidate, threshold),
+        Some(Command::NewDay { day, year, title }) => {
+            let new_day = NewDay {
+                day,
+                year,
+                title: title.unwrap_or_else(|| format!("Day {}", day)),
+            };
+            return new_day_files(&new_day);
+        }
+        Some(Command::RunDay) => {
+            return match isolate::run_day_from_stdin() {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("Couldn't run the day: {}", err);
+                    ExitCode::FAILURE
+                }
+            };
+        }
+        None => (),
+    }
+    if cli.bench == Some(BenchKind::Wall) && cli.format.is_some() {
+        eprintln!("--format isn't supported with --bench=wall");
+        return ExitCode::FAILURE;
+    }
+    if cli.memory_limit.is_some() && !alloc_stats::enabled() {
+        eprintln!("--memory-limit needs the allocator from the alloc-stats feature, try `cargo run --features alloc-stats`");
+        return ExitCode::FAILURE;
+    }
+    let limits = Limits {
+        time: cli.timeout,
+        memory: cli.memory_limit.map(|mib| mib.saturating_mul(1024 * 1024)),
+    };
+    let year = cli.year;
+    if !registry::years().any(|registered| registered == year) {
+        eprintln!(
+            "There aren't any solutions for {}. Years with solutions: {}",
+            year,
+            registry::years()
+                .map(|year| year.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return ExitCode::FAILURE;
+    }
+    let input_dir = InputDir::new(cli.inputs);
+    let selection = cli.days.unwrap_or_default();
+    let custom_input = cli.input.as_deref().map(InputSource::from_arg);
+    if custom_input.is_some() {
+        if !matches!(&selection, DaySelection::Days(days) if days.len() == 1) {
+            eprintln!(
+                "--input needs a single day to run, e.g. `cargo run -- 5 --input edge_case.txt`"
+            );
+            return ExitCode::FAILURE;
+        }
+        if cli.bench == Some(BenchKind::Iai) {
+            eprintln!("--input isn't supported with iai, which always reads the input directory");
+            return ExitCode::FAILURE;
+        }
+    }
+    let solutions_to_run = match &selection {
+        DaySelection::All => registry::solutions(year)
+            // Skip day0 example
+            .filter(|solution| solution.day() != 0)
+            .collect::<Vec<_>>(),
+        DaySelection::Days(days) => {
+            let unknown = days
+                .iter()
+                .copied()
+                .filter(|day| registry::get(year, *day).is_none())
+                .collect::<Vec<_>>();
+            if !unknown.is_empty() {
+                let known = registry::solutions(year)
+                    .map(|solution| solution.day())
+                    .collect::<Vec<_>>();
+                eprintln!(
+                    "There's no solution for {} day {}. Days with solutions: {}",
+                    year,
+                    DayList(&unknown),
+                    DayList(&known)
+                );
+                return ExitCode::FAILURE;
+            }
+            days.iter()
+                .filter_map(|day| registry::get(year, *day))
+                .collect()
+        }
+    };
+
+    let answers = if cli.check {
+        match Answers::load(&input_dir.answers_path(year)) {
+            Ok(answers) => answers,
+            Err(err) => {
+                eprintln!("{}", err);
+                return ExitCode::FAILURE;
+            }
         }
-        days_to_execute.push(day);
     } else {
-        days_to_execute = days.drain(1..).collect(); // Skip day0 example
+        Answers::default()
+    };
+    let bench_config = BenchConfig {
+        samples: cli.samples,
+        ..Default::default()
+    };
+    let mut check_report = CheckReport::default();
+    let mut run_report = RunReport::default();
+    let mut iai_report = IaiReport::default();
+    let mut recorder = Recorder::now(year);
+    // Timings from days running in parallel aren't worth showing
+    let reporter = Reporter {
+        show_timings: cli.jobs == 1,
+    };
+
+    let mut skipped = vec![];
+    // Days to solve in parallel once every input has been loaded
+    let mut parallel = vec![];
+    for solution in solutions_to_run {
+        let day = solution.day();
+        if cli.every_input {
+            let inputs = match input_dir.inputs_for(year, day) {
+                Ok(inputs) => inputs,
+                Err(InputError::Missing { .. }) if selection == DaySelection::All => {
+                    skipped.push(day);
+                    continue;
+                }
+                Err(err) => {
+                    let result = DayResult::failed(day, AocError::new(err.to_string()));
+                    reporter.start_day(day, solution.title(), None);
+                    reporter.finish_day(&result);
+                    let input_source = InputSource::File(input_dir.path_for(year, day));
+                    run_report.push(solution.title(), &input_source, &result);
+                    continue;
+                }
+            };
+            for path in inputs {
+                let input_source = InputSource::File(path);
+                reporter.start_day(day, solution.title(), Some(&input_source));
+                let result = match input_source.read() {
+                    Ok(input_lines) => {
+                        let input_lines = prepare_input(&input_lines, &input_source);
+                        run_day(year, solution, &input_lines, cli.part, limits)
+                    }
+                    Err(err) => DayResult::failed(
+                        day,
+                        AocError::new(format!("Couldn't read input: {}", err)),
+                    ),
+                };
+                reporter.finish_day(&result);
+                run_report.push(solution.title(), &input_source, &result);
+            }
+            continue;
+        }
+        let (input_lines, input_source) = match &custom_input {
+            Some(source) => match source.read() {
+                Ok(input_lines) => (input_lines, source.clone()),
+                Err(err) => {
+                    eprintln!("Couldn't read input from {}: {}", source, err);
+                    return ExitCode::FAILURE;
+                }
+            },
+            None => match input_dir.load(year, day) {
+                Ok(input_lines) => (
+                    input_lines,
+                    InputSource::File(input_dir.path_for(year, day)),
+                ),
+                // When running everything, days without an input just haven't been done yet
+                Err(InputError::Missing { .. }) if selection == DaySelection::All => {
+                    skipped.push(day);
+                    continue;
+                }
+                Err(err) if cli.bench.is_some() => {
+                    eprintln!("{}", err);
+                    continue;
+                }
+                // A day that was asked for by name fails without its input, so --check notices
+                Err(err) => {
+                    let result = DayResult::failed(day, AocError::new(err.to_string()));
+                    reporter.start_day(day, solution.title(), None);
+                    reporter.finish_day(&result);
+                    check_report.record(&answers, cli.part, &result);
+                    let input_source = InputSource::File(input_dir.path_for(year, day));
+                    run_report.push(solution.title(), &input_source, &result);
+                    continue;
+                }
+            },
+        };
+        let input_lines = prepare_input(&input_lines, &input_source);
+        if cli.bench == Some(BenchKind::Iai) {
+            println!("Benchmarking {} day {}: {}...", year, day, solution.title());
+            match bench_day(&year, &day) {
+                Ok(benches) => {
+                    recorder.record_iai(day, &benches);
+                    iai_report.push(day, benches);
+                }
+                Err(err) => eprintln!("{}", err),
+            }
+        } else if cli.bench == Some(BenchKind::Wall) {
+            println!("Benchmarking {} day {}: {}...", year, day, solution.title());
+            match solution.bench(&input_lines, &bench_config) {
+                Ok(result) => {
+                    recorder.record_wall(day, &result);
+                    println!("{}", result);
+                }
+                Err(err) => println!("Failed - {}", err),
+            }
+        } else if cli.jobs != 1 {
+            parallel.push((solution, input_lines, input_source));
+        } else {
+            reporter.start_day(day, solution.title(), custom_input.as_ref());
+            let result = run_day(year, solution, &input_lines, cli.part, limits);
+            reporter.finish_day(&result);
+            if result.error.is_none() && !result.answers.skewed {
+                recorder.record_run(day, &result.answers);
+            }
+            check_report.record(&answers, cli.part, &result);
+            run_report.push(solution.title(), &input_source, &result);
+        }
     }
-    for day in days_to_execute {
-        if cli.bench {
-            bench_day(&day);
+    if !parallel.is_empty() {
+        let pool = match rayon::ThreadPoolBuilder::new()
+            .num_threads(cli.jobs)
+            .build()
+        {
+            Ok(pool) => pool,
+            Err(err) => {
+                eprintln!("Couldn't start {} threads: {}", cli.jobs, err);
+                return ExitCode::FAILURE;
+            }
+        };
+        let results = pool.install(|| {
+            parallel
+                .par_iter()
+                .map(|(solution, input_lines, _)| solution.run(input_lines, cli.part, limits))
+                .collect::<Vec<_>>()
+        });
+        for ((solution, _, input_source), mut result) in parallel.iter().zip(results) {
+            // Heap usage is counted process-wide, so it's meaningless with other days running, and
+            // the timings are skewed by them
+            result.answers.allocations = None;
+            reporter.start_day(solution.day(), solution.title(), custom_input.as_ref());
+            reporter.finish_day(&result);
+            check_report.record(&answers, cli.part, &result);
+            run_report.push_untimed(solution.title(), input_source, &result);
+        }
+    }
+    if !skipped.is_empty() {
+        println!("==========");
+        println!(
+            "Skipped days without an input in {}: {}",
+            input_dir.root().join(year.to_string()).display(),
+            DayList(&skipped)
+        );
+    }
+
+    if cli.every_input {
+        println!("==========");
+        print!("{}", run_report);
+    }
+
+    // Timings for other inputs, or taken alongside other days, aren't comparable with the history,
+    // so only keep the usual ones
+    if custom_input.is_none() && !cli.every_input && cli.jobs == 1 {
+        if let Err(err) = recorder.save(Path::new(HISTORY_FILE)) {
+            eprintln!("Couldn't record timings in {}: {}", HISTORY_FILE, err);
+        }
+    }
+
+    if cli.bench == Some(BenchKind::Iai) {
+        println!("==========");
+        print!("{}", iai_report);
+    }
+
+    if let Some(format) = cli.format {
+        let report = if cli.bench == Some(BenchKind::Iai) {
+            iai_report.render(format)
         } else {
-            solve_day(&day, true);
+            run_report.render(format)
+        };
+        match cli.output {
+            Some(path) => {
+                if let Err(err) = fs::write(&path, report) {
+                    eprintln!("Couldn't write report to {}: {}", path.display(), err);
+                    return ExitCode::FAILURE;
+                }
+            }
+            None => {
+                println!("==========");
+                print!("{}", report);
+            }
+        }
+    }
+
+    if cli.check {
+        println!("==========");
+        println!("{}", check_report);
+        if check_report.any_failed() {
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+/// Solve a day, in a process of its own if it has a memory limit to be held to, see [`isolate`].
+fn run_day(
+    year: i32,
+    solution: &dyn DynSolution,
+    input_lines: &str,
+    parts: Parts,
+    limits: Limits,
+) -> DayResult {
+    if limits.memory.is_some() {
+        isolate::run_in_child(year, solution.day(), input_lines, parts, limits)
+    } else {
+        solution.run(input_lines, parts, limits)
+    }
+}
+
+/// Tidy up an input before any solution sees it, warning about anything that had to change.
+fn prepare_input(input_lines: &str, source: &InputSource) -> String {
+    let (input_lines, fixes) = input::normalise(input_lines);
+    if let Some((last, rest)) = fixes.split_last() {
+        let mut fixed = rest
+            .iter()
+            .map(Fix::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        if !fixed.is_empty() {
+            fixed += " and ";
+        }
+        eprintln!(
+            "Warning: tidied up {} before solving: {}{}",
+            source, fixed, last
+        );
+    }
+    input_lines
+}
+
+fn parse_seconds(seconds: &str) -> Result<Duration, String> {
+    seconds
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| format!("'{}' isn't a positive number of seconds", seconds))
+}
+
+fn compare(baseline: Option<String>, candidate: Option<String>, threshold: f64) -> ExitCode {
+    let history = match History::load(Path::new(HISTORY_FILE)) {
+        Ok(history) => history,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let resolve = |name: Option<String>, default: Option<&str>, which: &str| {
+        match name {
+        Some(name) => history.resolve_commit(&name).map(str::to_string).ok_or(format!(
+            "Nothing has been recorded for {} commit {}",
+            which, name
+        )),
+        None => default.map(str::to_string).ok_or(format!(
+            "Need measurements from at least two commits to pick a {}, run or bench some days first",
+            which
+        )),
+    }
+    };
+    let (latest, previous) = history.latest_commits();
+    let candidate = match resolve(candidate, latest, "candidate") {
+        Ok(candidate) => candidate,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    // Without an explicit baseline, use whichever commit was measured before the candidate
+    let previous = if Some(candidate.as_str()) == latest {
+        previous
+    } else {
+        latest
+    };
+    let baseline = match resolve(baseline, previous, "baseline") {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    let comparison = history.compare(&baseline, &candidate, threshold);
+    println!("{}", comparison);
+    if comparison.any_regressed() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn new_day_files(new_day: &NewDay) -> ExitCode {
+    match new_day.generate(Path::new(env!("CARGO_MANIFEST_DIR"))) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            println!(
+                "Save your input for day {} in {}",
+                new_day.day,
+                InputDir::default()
+                    .path_for(new_day.year, new_day.day)
+                    .display()
+            );
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
         }
     }
 }
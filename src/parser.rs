@@ -0,0 +1,272 @@
+//! A small cursor for picking apart a line of puzzle input, e.g. `Game 12: 3 blue, 4 red`.
+//! Everything it reads skips any whitespace in front first, and its errors say where on the line
+//! things went wrong, so a solution can return them straight from `parse_input`.
+//!
+//! ```
+//! # use advent_of_code_template::{error::AocResult, parser::Parser};
+//! # fn main() -> AocResult<()> {
+//! let mut parser = Parser::new("Card 3: 1 21 | -5");
+//! parser.expect_literal("Card")?;
+//! let id: u32 = parser.parse_int()?;
+//! parser.expect_literal(":")?;
+//! let winners = parser.parse_separated(' ', |parser| parser.parse_int::<i64>())?;
+//! parser.expect_literal("|")?;
+//! assert_eq!((id, winners, parser.parse_int::<i8>()?), (3, vec![1, 21], -5));
+//! # Ok(())
+//! # }
+//! ```
+
+use std::{num::ParseIntError, str::FromStr};
+
+use crate::error::AocError;
+
+/// Reads through a piece of text from the start, keeping track of the column for errors.
+#[derive(Clone, Debug)]
+pub struct Parser<'a> {
+    rest: &'a str,
+    line: Option<usize>,
+    /// 1-based column of the next char.
+    column: usize,
+}
+
+/// A parser for each line of `input`, which report errors on the right line.
+pub fn lines(input: &str) -> impl Iterator<Item = Parser<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| Parser::new(line).on_line(i + 1))
+}
+
+impl<'a> Parser<'a> {
+    pub fn new(text: &'a str) -> Self {
+        Parser {
+            rest: text,
+            line: None,
+            column: 1,
+        }
+    }
+
+    /// Report errors as being on `line` (1-based) of the input.
+    pub fn on_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// 1-based column of the next char.
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    /// Everything that hasn't been read yet.
+    pub fn rest(&self) -> &'a str {
+        self.rest
+    }
+
+    /// An error at the current position.
+    pub fn error(&self, message: impl Into<String>) -> AocError {
+        self.error_at(self.column, message)
+    }
+
+    /// An error at an earlier `column`, e.g. the start of something that turned out to be wrong.
+    pub fn error_at(&self, column: usize, message: impl Into<String>) -> AocError {
+        let err = AocError::new(message).at_column(column);
+        match self.line {
+            Some(line) => err.at_line(line),
+            None => err,
+        }
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest.chars().next()
+    }
+
+    pub fn next_char(&mut self) -> Option<char> {
+        let next = self.peek()?;
+        self.advance(next.len_utf8());
+        Some(next)
+    }
+
+    /// Whether everything but whitespace has been read.
+    pub fn is_done(&mut self) -> bool {
+        self.skip_whitespace();
+        self.rest.is_empty()
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Skip `n` chars, whatever they are.
+    pub fn skip(&mut self, n: usize) -> Result<(), AocError> {
+        for _ in 0..n {
+            self.next_char()
+                .ok_or_else(|| self.error("Line ended too early"))?;
+        }
+        Ok(())
+    }
+
+    /// Step over `c` if it's next, for optional separators.
+    pub fn step_over(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(c) {
+            self.advance(c.len_utf8());
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Step over `literal`, which has to come next.
+    pub fn expect_literal(&mut self, literal: &str) -> Result<(), AocError> {
+        self.skip_whitespace();
+        if self.rest.starts_with(literal) {
+            self.advance(literal.len());
+            Ok(())
+        } else {
+            Err(self.error(format!("Expected '{}'", literal)))
+        }
+    }
+
+    /// Read a whole number, with an optional sign, as any integer type.
+    pub fn parse_int<T>(&mut self) -> Result<T, AocError>
+    where
+        T: FromStr<Err = ParseIntError>,
+    {
+        self.skip_whitespace();
+        let start = self.column;
+        let sign_len = usize::from(self.rest.starts_with(['-', '+']));
+        let digits_len = self.rest[sign_len..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(self.rest.len() - sign_len);
+        if digits_len == 0 {
+            return Err(self.error("Expected a number"));
+        }
+        let number = &self.rest[..sign_len + digits_len];
+        self.advance(number.len());
+        number
+            .parse()
+            .map_err(|err| self.error_at(start, AocError::from(err).message))
+    }
+
+    /// Read a run of letters.
+    pub fn parse_word(&mut self) -> Result<&'a str, AocError> {
+        self.skip_whitespace();
+        let word = self.take_while(char::is_alphabetic);
+        if word.is_empty() {
+            Err(self.error("Expected a word"))
+        } else {
+            Ok(word)
+        }
+    }
+
+    /// Read one or more items with `item`, separated by `separator`, e.g. `3 blue, 4 red`.
+    /// A space separator allows any amount of whitespace between items.
+    pub fn parse_separated<T>(
+        &mut self,
+        separator: char,
+        mut item: impl FnMut(&mut Self) -> Result<T, AocError>,
+    ) -> Result<Vec<T>, AocError> {
+        let mut items = vec![item(self)?];
+        loop {
+            let before = self.clone();
+            if separator == ' ' {
+                self.skip_whitespace();
+                if self.column == before.column || self.rest.is_empty() {
+                    break;
+                }
+            } else if !self.step_over(separator) {
+                break;
+            }
+            // Trailing spaces, or a space before something else, end the list
+            match item(self) {
+                Ok(next) => items.push(next),
+                Err(_) if separator == ' ' => {
+                    *self = before;
+                    break;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+        Ok(items)
+    }
+
+    fn take_while(&mut self, mut predicate: impl FnMut(char) -> bool) -> &'a str {
+        let len = self.rest.find(|c| !predicate(c)).unwrap_or(self.rest.len());
+        let taken = &self.rest[..len];
+        self.advance(len);
+        taken
+    }
+
+    fn advance(&mut self, bytes: usize) {
+        self.column += self.rest[..bytes].chars().count();
+        self.rest = &self.rest[bytes..];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_parse_game() {
+        let mut parser = Parser::new("Game 12: 3 blue, 4 red; 1 green");
+        parser.expect_literal("Game").unwrap();
+        assert_eq!(parser.parse_int::<u8>(), Ok(12));
+        parser.expect_literal(":").unwrap();
+        let turns = parser
+            .parse_separated(';', |parser| {
+                parser.parse_separated(',', |parser| {
+                    Ok((parser.parse_int::<u32>()?, parser.parse_word()?))
+                })
+            })
+            .unwrap();
+        assert_eq!(turns, [vec![(3, "blue"), (4, "red")], vec![(1, "green")]]);
+        assert!(parser.is_done());
+    }
+
+    #[test]
+    fn check_parse_numbers() {
+        let mut parser = Parser::new(" -3 +4 17 300 x");
+        assert_eq!(parser.parse_int::<i64>(), Ok(-3));
+        assert_eq!(parser.parse_int::<i32>(), Ok(4));
+        assert_eq!(parser.parse_int::<u8>(), Ok(17));
+        assert_eq!(
+            parser.parse_int::<u8>().unwrap_err().to_string(),
+            "column 11: Invalid number (number too large to fit in target type)"
+        );
+        assert_eq!(
+            parser.parse_int::<u8>().unwrap_err().to_string(),
+            "column 15: Expected a number"
+        );
+    }
+
+    #[test]
+    fn check_space_separated_lists() {
+        let mut parser = Parser::new("41 48  6 | 83 86");
+        let numbers = parser.parse_separated(' ', |parser| parser.parse_int::<u8>());
+        assert_eq!(numbers, Ok(vec![41, 48, 6]));
+        parser.expect_literal("|").unwrap();
+        let numbers = parser.parse_separated(' ', |parser| parser.parse_int::<u8>());
+        assert_eq!(numbers, Ok(vec![83, 86]));
+    }
+
+    #[test]
+    fn check_errors_have_positions() {
+        let mut parsers = lines("AAA = (BBB, CCC)\nBBB = [DDD, EEE)");
+        let mut first = parsers.next().unwrap();
+        let mut second = parsers.next().unwrap();
+        for parser in [&mut first, &mut second] {
+            parser.parse_word().unwrap();
+            parser.expect_literal("=").unwrap();
+        }
+        assert_eq!(first.expect_literal("("), Ok(()));
+        assert_eq!(
+            second.expect_literal("(").unwrap_err().to_string(),
+            "line 2:7: Expected '('"
+        );
+        assert_eq!(
+            Parser::new("Game").skip(5).unwrap_err().to_string(),
+            "column 5: Line ended too early"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+//! Positions and directions for walking around a map. `y` increases going down the input, so
+//! [`Dir4::North`] is towards the first line, matching [`crate::grid::Grid`]'s coordinates.
+
+use std::ops::{Add, AddAssign, Mul, Sub};
+
+use crate::error::{AocError, AocResult};
+
+/// A position that, unlike a grid coordinate, can step off the top or left of the map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    /// The next point over in direction `dir`.
+    pub fn step(self, dir: impl Into<Dir8>) -> Self {
+        self + dir.into().offset()
+    }
+
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The grid coordinate for this point, if it isn't off the top or left.
+    pub fn to_coord(self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((x, y): (usize, usize)) -> Self {
+        Point::new(x as isize, y as isize)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Point {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Point {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, scale: isize) -> Point {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+/// One of the four directions along the grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Dir4 {
+    /// Clockwise from north.
+    pub const ALL: [Dir4; 4] = [Dir4::North, Dir4::East, Dir4::South, Dir4::West];
+
+    /// Read `U`/`D`/`L`/`R`, `N`/`E`/`S`/`W`, or an arrow like `^` or `→`.
+    pub fn from_char(c: char) -> AocResult<Self> {
+        match c {
+            'U' | 'N' | '^' | '↑' => Ok(Dir4::North),
+            'R' | 'E' | '>' | '→' => Ok(Dir4::East),
+            'D' | 'S' | 'v' | '↓' => Ok(Dir4::South),
+            'L' | 'W' | '<' | '←' => Ok(Dir4::West),
+            _ => Err(AocError::new(format!("Unexpected direction '{}'", c))),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+
+    /// How far one step in this direction moves.
+    pub fn offset(self) -> Point {
+        Dir8::from(self).offset()
+    }
+}
+
+/// One of the four directions along the grid, or the four diagonals between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Dir8 {
+    /// Clockwise from north.
+    pub const ALL: [Dir8; 8] = [
+        Dir8::North,
+        Dir8::NorthEast,
+        Dir8::East,
+        Dir8::SouthEast,
+        Dir8::South,
+        Dir8::SouthWest,
+        Dir8::West,
+        Dir8::NorthWest,
+    ];
+
+    /// Read anything [`Dir4::from_char`] does, or a diagonal arrow like `↗`.
+    pub fn from_char(c: char) -> AocResult<Self> {
+        match c {
+            '↗' => Ok(Dir8::NorthEast),
+            '↘' => Ok(Dir8::SouthEast),
+            '↙' => Ok(Dir8::SouthWest),
+            '↖' => Ok(Dir8::NorthWest),
+            _ => Dir4::from_char(c).map(Dir8::from),
+        }
+    }
+
+    /// An eighth of a turn clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// An eighth of a turn anticlockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    /// How far one step in this direction moves.
+    pub fn offset(self) -> Point {
+        match self {
+            Dir8::North => Point::new(0, -1),
+            Dir8::NorthEast => Point::new(1, -1),
+            Dir8::East => Point::new(1, 0),
+            Dir8::SouthEast => Point::new(1, 1),
+            Dir8::South => Point::new(0, 1),
+            Dir8::SouthWest => Point::new(-1, 1),
+            Dir8::West => Point::new(-1, 0),
+            Dir8::NorthWest => Point::new(-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::ALL[dir as usize * 2]
+    }
+}
+
+impl TryFrom<Dir8> for Dir4 {
+    type Error = AocError;
+
+    fn try_from(dir: Dir8) -> AocResult<Self> {
+        match dir {
+            Dir8::North => Ok(Dir4::North),
+            Dir8::East => Ok(Dir4::East),
+            Dir8::South => Ok(Dir4::South),
+            Dir8::West => Ok(Dir4::West),
+            diagonal => Err(AocError::new(format!("{:?} is diagonal", diagonal))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_turns() {
+        assert_eq!(Dir4::North.turn_left(), Dir4::West);
+        assert_eq!(Dir4::West.turn_right(), Dir4::North);
+        assert_eq!(Dir4::East.opposite(), Dir4::West);
+        assert_eq!(Dir8::North.turn_left(), Dir8::NorthWest);
+        assert_eq!(Dir8::SouthWest.opposite(), Dir8::NorthEast);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_right().turn_right(), dir.opposite());
+            assert_eq!(dir.offset() + dir.opposite().offset(), Point::ORIGIN);
+        }
+    }
+
+    #[test]
+    fn check_steps() {
+        let start = Point::new(2, 0);
+        let end = start
+            .step(Dir4::South)
+            .step(Dir8::SouthWest)
+            .step(Dir4::West);
+        assert_eq!(end, Point::new(0, 2));
+        assert_eq!(start.manhattan(end), 4);
+        assert_eq!(end.step(Dir4::West).step(Dir4::West).to_coord(), None);
+        assert_eq!(Point::from((3, 4)).to_coord(), Some((3, 4)));
+    }
+
+    #[test]
+    fn check_parse_directions() {
+        let dirs = "URDL NESW ^>v< ↑→↓←"
+            .chars()
+            .filter(|c| *c != ' ')
+            .map(Dir4::from_char)
+            .collect::<AocResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(dirs, Dir4::ALL.repeat(4));
+        assert_eq!(Dir8::from_char('↙'), Ok(Dir8::SouthWest));
+        assert_eq!(Dir8::from_char('L'), Ok(Dir8::West));
+        assert_eq!(
+            Dir4::from_char('x').unwrap_err().to_string(),
+            "Unexpected direction 'x'"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+//! Every year's solutions, in one place.
+//! Each year lives in its own `yYYYY` module with a `SOLUTIONS` list of its days. When adding a new
+//! `DayXX`, add it to that list so the runner and benches can find it, and when starting a new year
+//! add its module to [`YEARS`]. `cargo run -- new-day <DAY> --year <YEAR>` does all of this for you.
+
+use crate::DynSolution;
+
+/// The year that's run when no `--year` is given.
+pub const DEFAULT_YEAR: i32 = 2023;
+
+/// Every year with solutions, oldest first.
+pub static YEARS: &[(i32, &[&dyn DynSolution])] = &[(2023, crate::y2023::SOLUTIONS)];
+
+/// Iterate over the years that have solutions.
+pub fn years() -> impl Iterator<Item = i32> {
+    YEARS.iter().map(|(year, _)| *year)
+}
+
+/// Iterate over a year's registered solutions in day order.
+/// Years without any solutions are empty.
+pub fn solutions(year: i32) -> impl Iterator<Item = &'static dyn DynSolution> {
+    YEARS
+        .iter()
+        .filter(move |(registered, _)| *registered == year)
+        .flat_map(|(_, solutions)| solutions.iter().copied())
+}
+
+/// Look up the solution for a single day.
+pub fn get(year: i32, day: i32) -> Option<&'static dyn DynSolution> {
+    solutions(year).find(|solution| solution.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_registry_days_are_unique_and_ordered() {
+        let days = solutions(2023)
+            .map(|solution| solution.day())
+            .collect::<Vec<_>>();
+        assert_eq!(days, (0..=25).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn check_registry_lookup() {
+        assert_eq!(
+            get(2023, 7).map(|solution| solution.title()),
+            Some("Camel Cards")
+        );
+        assert!(get(2023, 26).is_none());
+        assert!(get(1999, 7).is_none());
+        assert!(years().any(|year| year == DEFAULT_YEAR));
+    }
+}
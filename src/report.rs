@@ -0,0 +1,461 @@
+//! Machine-readable summaries of a run, for keeping track of answers and timings over time.
+
+use std::{fmt, time::Duration};
+
+use clap::ValueEnum;
+use serde_json::json;
+
+use crate::{
+    alloc_stats::{AllocStats, PhaseAllocs},
+    error::Part,
+    input::InputSource,
+    DayResult, Timings,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+    /// A table that can be pasted straight into the README.
+    Markdown,
+}
+
+/// How a single day went.
+#[derive(Clone, Debug)]
+pub struct DayReport {
+    pub title: &'static str,
+    /// Where the input came from, e.g. its path.
+    pub input: String,
+    /// The answers and timings of the phases that finished, and the error that stopped the rest.
+    pub result: DayResult,
+    /// Whether the timings are worth reporting. They aren't when other days, or a phase that
+    /// timed out earlier, were running at the same time.
+    pub timed: bool,
+}
+
+impl DayReport {
+    /// How long a phase took, if it finished and the timings are worth reporting.
+    fn time(&self, part: Part) -> Option<Duration> {
+        let timings = &self.result.answers.timings;
+        let time = match part {
+            Part::Parse => timings.parse,
+            Part::One => timings.part_one,
+            Part::Two => timings.part_two,
+        };
+        (self.timed && self.result.completed(part)).then_some(time)
+    }
+
+    /// How long the whole day took, if every phase finished.
+    fn total_time(&self) -> Option<Duration> {
+        (self.timed && self.result.error.is_none()).then(|| self.result.answers.timings.total())
+    }
+
+    fn error(&self) -> Option<String> {
+        self.result.error.as_ref().map(|err| err.to_string())
+    }
+
+    /// The answer to show for `part`, the error after `failed` if that's what stopped it, or `-`
+    /// if it didn't run. An error from before either part started goes under part one.
+    fn answer_or_error(&self, part: Part, failed: &str) -> String {
+        if let Some(answer) = self.result.answers.answer(part) {
+            return answer.to_string();
+        }
+        match &self.result.error {
+            Some(err) if (err.part == Some(Part::Two)) == (part == Part::Two) => {
+                format!("{}{}", failed, err)
+            }
+            _ => "-".to_string(),
+        }
+    }
+}
+
+/// Results of every day that ran, in the order they were run.
+#[derive(Clone, Debug, Default)]
+pub struct RunReport {
+    days: Vec<DayReport>,
+}
+
+impl RunReport {
+    pub fn push(&mut self, title: &'static str, input: &InputSource, result: &DayResult) {
+        self.days.push(DayReport {
+            title,
+            input: input.to_string(),
+            result: result.clone(),
+            timed: !result.answers.skewed,
+        });
+    }
+
+    /// Add a day's answers, leaving its timings out of the report and the total.
+    pub fn push_untimed(&mut self, title: &'static str, input: &InputSource, result: &DayResult) {
+        self.push(title, input, result);
+        if let Some(day) = self.days.last_mut() {
+            day.timed = false;
+        }
+    }
+
+    pub fn days(&self) -> &[DayReport] {
+        &self.days
+    }
+
+    /// Time spent in each phase, summed over every timed day that succeeded.
+    pub fn total(&self) -> Timings {
+        self.days
+            .iter()
+            .filter(|day| day.total_time().is_some())
+            .map(|day| &day.result.answers.timings)
+            .fold(Timings::default(), |total, timings| Timings {
+                parse: total.parse + timings.parse,
+                part_one: total.part_one + timings.part_one,
+                part_two: total.part_two + timings.part_two,
+            })
+    }
+
+    pub fn render(&self, format: ReportFormat) -> String {
+        match format {
+            ReportFormat::Json => self.to_json(),
+            ReportFormat::Csv => self.to_csv(),
+            ReportFormat::Markdown => self.to_markdown(),
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let days = self
+            .days
+            .iter()
+            .map(|day| {
+                let answers = &day.result.answers;
+                let mut json = json!({
+                    "day": day.result.day,
+                    "title": day.title,
+                    "input": day.input,
+                    "part_one": answers.part_one,
+                    "part_two": answers.part_two,
+                });
+                if day.timed {
+                    let micros = |time: Option<Duration>| time.map(micros);
+                    json["timings_us"] = json!({
+                        "parse": micros(day.time(Part::Parse)),
+                        "part_one": micros(day.time(Part::One)),
+                        "part_two": micros(day.time(Part::Two)),
+                        "total": micros(day.total_time()),
+                    });
+                }
+                if let Some(allocations) = &answers.allocations {
+                    json["allocations"] = allocations_json(allocations);
+                }
+                if let Some(err) = day.error() {
+                    json["error"] = json!(err);
+                }
+                json
+            })
+            .collect::<Vec<_>>();
+        let report = json!({
+            "days": days,
+            "total_us": timings_json(&self.total()),
+        });
+        serde_json::to_string_pretty(&report).expect("Report is always valid JSON")
+    }
+
+    fn to_csv(&self) -> String {
+        let mut csv =
+            "day,title,input,part_one,part_two,parse_us,part_one_us,part_two_us,total_us,error\n"
+                .to_string();
+        for day in &self.days {
+            let answers = &day.result.answers;
+            let micros = |time: Option<Duration>| time.map(|time| micros(time).to_string());
+            let fields = [
+                day.result.day.to_string(),
+                day.title.to_string(),
+                day.input.clone(),
+                answers.part_one.clone().unwrap_or_default(),
+                answers.part_two.clone().unwrap_or_default(),
+                micros(day.time(Part::Parse)).unwrap_or_default(),
+                micros(day.time(Part::One)).unwrap_or_default(),
+                micros(day.time(Part::Two)).unwrap_or_default(),
+                micros(day.total_time()).unwrap_or_default(),
+                day.error().unwrap_or_default(),
+            ];
+            csv += &fields.map(|field| csv_field(&field)).join(",");
+            csv.push('\n');
+        }
+        let total = self.total();
+        csv += &format!(
+            "total,,,,,{},{},{},{},\n",
+            micros(total.parse),
+            micros(total.part_one),
+            micros(total.part_two),
+            micros(total.total())
+        );
+        csv
+    }
+
+    fn to_markdown(&self) -> String {
+        let mut table =
+            "| Day | Title | Input | Part 1 | Part 2 | Parsing | Part 1 time | Part 2 time | Total |\n\
+            |---:|---|---|---|---|---:|---:|---:|---:|\n"
+                .to_string();
+        for day in &self.days {
+            let duration = |time: Option<Duration>| time.map_or("-".to_string(), format_duration);
+            table += &format!(
+                "| {} | {} | {} | {} | {} | {} | {} | {} | {} |\n",
+                day.result.day,
+                markdown_cell(day.title),
+                markdown_cell(&day.input),
+                markdown_cell(&day.answer_or_error(Part::One, "Failed: ")),
+                markdown_cell(&day.answer_or_error(Part::Two, "Failed: ")),
+                duration(day.time(Part::Parse)),
+                duration(day.time(Part::One)),
+                duration(day.time(Part::Two)),
+                duration(day.total_time()),
+            );
+        }
+        let total = self.total();
+        table += &format!(
+            "| | **Total** | | | | {} | {} | {} | **{}** |\n",
+            format_duration(total.parse),
+            format_duration(total.part_one),
+            format_duration(total.part_two),
+            format_duration(total.total()),
+        );
+        table
+    }
+}
+
+/// A summary of the answers from each input, for comparing runs of the same day over several.
+impl fmt::Display for RunReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let rows = self
+            .days
+            .iter()
+            .map(|day| {
+                [
+                    day.result.day.to_string(),
+                    day.input.clone(),
+                    day.answer_or_error(Part::One, "Failed - "),
+                    day.answer_or_error(Part::Two, "Failed - "),
+                ]
+            })
+            .collect::<Vec<_>>();
+        let header = ["Day", "Input", "Part 1", "Part 2"].map(str::to_string);
+        let mut widths = header.clone().map(|cell| cell.chars().count());
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for row in std::iter::once(&header).chain(&rows) {
+            let line = format!(
+                "{:>w0$}  {:<w1$}  {:<w2$}  {}",
+                row[0],
+                row[1],
+                row[2],
+                row[3],
+                w0 = widths[0],
+                w1 = widths[1],
+                w2 = widths[2],
+            );
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+fn timings_json(timings: &Timings) -> serde_json::Value {
+    json!({
+        "parse": micros(timings.parse),
+        "part_one": micros(timings.part_one),
+        "part_two": micros(timings.part_two),
+        "total": micros(timings.total()),
+    })
+}
+
+fn allocations_json(allocations: &PhaseAllocs) -> serde_json::Value {
+    let phase = |stats: &AllocStats| {
+        json!({
+            "allocations": stats.allocations,
+            "bytes_allocated": stats.bytes_allocated,
+            "peak_live_bytes": stats.peak_live_bytes,
+        })
+    };
+    json!({
+        "parse": phase(&allocations.parse),
+        "part_one": phase(&allocations.part_one),
+        "part_two": phase(&allocations.part_two),
+    })
+}
+
+fn micros(duration: Duration) -> f64 {
+    duration.as_nanos() as f64 / 1000.0
+}
+
+/// Format a duration in whichever unit keeps it readable.
+pub fn format_duration(duration: Duration) -> String {
+    let micros = micros(duration);
+    if micros < 1.0 {
+        format!("{} ns", duration.as_nanos())
+    } else if micros < 1000.0 {
+        format!("{:.0} μs", micros)
+    } else if micros < 1_000_000.0 {
+        format!("{:.2} ms", micros / 1000.0)
+    } else {
+        format!("{:.2} s", micros / 1_000_000.0)
+    }
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn markdown_cell(cell: &str) -> String {
+    cell.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::AocError, TimedAnswers};
+
+    fn example_report() -> RunReport {
+        let mut report = RunReport::default();
+        report.push(
+            "Trebuchet?!",
+            &InputSource::File("inputs/2023/1".into()),
+            &DayResult {
+                day: 1,
+                answers: TimedAnswers {
+                    part_one: Some("142".to_string()),
+                    part_two: Some("281".to_string()),
+                    timings: Timings {
+                        parse: Duration::from_micros(5),
+                        part_one: Duration::from_micros(20),
+                        part_two: Duration::from_millis(3),
+                    },
+                    allocations: None,
+                    skewed: false,
+                },
+                error: None,
+            },
+        );
+        report.push(
+            "Cube Conundrum",
+            &InputSource::Stdin,
+            &DayResult::failed(2, AocError::new("Bad, input")),
+        );
+        report.push(
+            "Haunted Wasteland",
+            &InputSource::File("inputs/2023/8".into()),
+            &DayResult {
+                day: 8,
+                answers: TimedAnswers {
+                    part_one: Some("6".to_string()),
+                    timings: Timings {
+                        parse: Duration::from_micros(7),
+                        part_one: Duration::from_micros(40),
+                        part_two: Duration::ZERO,
+                    },
+                    ..Default::default()
+                },
+                error: Some(AocError::new("TIMEOUT after 1.00 s").during(8, Part::Two)),
+            },
+        );
+        report
+    }
+
+    #[test]
+    fn check_total_skips_failed_days() {
+        assert_eq!(
+            example_report().total().total(),
+            Duration::from_micros(3025)
+        );
+    }
+
+    #[test]
+    fn check_csv_report() {
+        assert_eq!(
+            example_report().render(ReportFormat::Csv),
+            "day,title,input,part_one,part_two,parse_us,part_one_us,part_two_us,total_us,error
+1,Trebuchet?!,inputs/2023/1,142,281,5,20,3000,3025,
+2,Cube Conundrum,stdin,,,,,,,\"Bad, input\"
+8,Haunted Wasteland,inputs/2023/8,6,,7,40,,,\"day 8, part 2: TIMEOUT after 1.00 s\"
+total,,,,,5,20,3000,3025,
+"
+        );
+    }
+
+    #[test]
+    fn check_markdown_report() {
+        let markdown = example_report().render(ReportFormat::Markdown);
+        assert!(markdown.contains(
+            "| 1 | Trebuchet?! | inputs/2023/1 | 142 | 281 | 5 μs | 20 μs | 3.00 ms | 3.02 ms |"
+        ));
+        assert!(markdown.contains("| 2 | Cube Conundrum | stdin | Failed: Bad, input | - |"));
+        assert!(markdown.contains(
+            "| 8 | Haunted Wasteland | inputs/2023/8 | 6 | Failed: day 8, part 2: TIMEOUT after \
+             1.00 s | 7 μs | 40 μs | - | - |"
+        ));
+        assert!(
+            markdown.ends_with("| | **Total** | | | | 5 μs | 20 μs | 3.00 ms | **3.02 ms** |\n")
+        );
+    }
+
+    #[test]
+    fn check_summary_table() {
+        assert_eq!(
+            example_report().to_string(),
+            "Day  Input          Part 1               Part 2
+  1  inputs/2023/1  142                  281
+  2  stdin          Failed - Bad, input  -
+  8  inputs/2023/8  6                    Failed - day 8, part 2: TIMEOUT after 1.00 s
+"
+        );
+    }
+
+    #[test]
+    fn check_untimed_days_are_blank() {
+        let mut report = example_report();
+        let result = DayResult {
+            day: 3,
+            ..report.days()[0].result.clone()
+        };
+        report.push_untimed(
+            "Gear Ratios",
+            &InputSource::File("inputs/2023/3".into()),
+            &result,
+        );
+        assert_eq!(report.total().total(), Duration::from_micros(3025));
+        assert!(report
+            .render(ReportFormat::Csv)
+            .contains("\n3,Gear Ratios,inputs/2023/3,142,281,,,,,\n"));
+        assert!(report
+            .render(ReportFormat::Markdown)
+            .contains("| 3 | Gear Ratios | inputs/2023/3 | 142 | 281 | - | - | - | - |"));
+        let json: serde_json::Value =
+            serde_json::from_str(&report.render(ReportFormat::Json)).unwrap();
+        assert!(json["days"][3].get("timings_us").is_none());
+        assert!(json["days"][0].get("timings_us").is_some());
+    }
+
+    #[test]
+    fn check_json_report() {
+        let json: serde_json::Value =
+            serde_json::from_str(&example_report().render(ReportFormat::Json)).unwrap();
+        assert_eq!(json["days"][0]["part_two"], "281");
+        assert_eq!(json["days"][0]["input"], "inputs/2023/1");
+        assert_eq!(json["days"][0]["timings_us"]["part_two"], 3000.0);
+        assert!(json["days"][0].get("allocations").is_none());
+        assert!(json["days"][0].get("error").is_none());
+        assert_eq!(json["days"][1]["error"], "Bad, input");
+        assert_eq!(json["days"][2]["part_one"], "6");
+        assert_eq!(json["days"][2]["timings_us"]["part_one"], 40.0);
+        assert!(json["days"][2]["timings_us"]["part_two"].is_null());
+        assert_eq!(
+            json["days"][2]["error"],
+            "day 8, part 2: TIMEOUT after 1.00 s"
+        );
+        assert_eq!(json["total_us"]["total"], 3025.0);
+    }
+}
@@ -0,0 +1,493 @@
+//! Generating the boilerplate for a new day: the solution stub, its bench, and the entries in
+//! `lib.rs`, the registry and `Cargo.toml` that tie them together.
+
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+const DAY_TEMPLATE: &str = r#"// https://adventofcode.com/__YEAR__/day/__DAY__
+use crate::{error::AocResult, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Day__PADDED__;
+
+impl Solution for Day__PADDED__ {
+    const DAY: i32 = __DAY__;
+    const TITLE: &'static str = __TITLE__;
+
+    type ParsedInput = String;
+
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        // Change the return type of this function by editing the ParsedInput type above.
+        // You can skip this and pass the raw string to each part.
+        // Alternatively, you can parse the input here, either working on the same mutable struct
+        // in parts one and two or passing a tuple with the data required for each part.
+        // `crate::parser` can pick apart each line and report where any problems are.
+        Ok(input_lines.to_string())
+    }
+
+    fn part_one(_parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        // TODO: implement part one
+        Ok(0.to_string())
+    }
+
+    fn part_two(_parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        // TODO: implement part two
+        Ok(0.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_day__PADDED___part1_case1() {
+        assert_eq!(Day__PADDED__::solve_part_one(""), "0".to_string())
+    }
+
+    #[test]
+    fn check_day__PADDED___part2_case1() {
+        assert_eq!(Day__PADDED__::solve_part_two(""), "0".to_string())
+    }
+
+    #[test]
+    fn check_day__PADDED___both_case1() {
+        assert_eq!(Day__PADDED__::solve(""), ("0".to_string(), "0".to_string()))
+    }
+}
+"#;
+
+const BENCH_TEMPLATE: &str = r#"use advent_of_code_template::{input::load_input, solve_day, y__YEAR__::day__PADDED__::Day__PADDED__, Solution};
+use once_cell::sync::Lazy;
+
+static INPUT: Lazy<String> =
+    Lazy::new(|| load_input(__YEAR__, __DAY__).unwrap_or_else(|err| panic!("{}", err)));
+
+fn parsing() {
+    Day__PADDED__::parse_input(&INPUT).unwrap();
+}
+
+fn parsing_and_part_one() {
+    Day__PADDED__::solve_part_one(&INPUT);
+}
+
+fn parsing_and_part_two() {
+    Day__PADDED__::solve_part_two(&INPUT);
+}
+
+fn whole_solution() {
+    solve_day(&__YEAR__, &__DAY__, &INPUT).into_result().unwrap();
+}
+
+fn main() {
+    // iai counts the whole process, less a calibration run that only gets this far, so loading
+    // the input before handing over keeps it out of every benchmark
+    Lazy::force(&INPUT);
+    let benchmarks: &[&(&'static str, fn())] = &[
+        &("parsing", parsing),
+        &("parsing_and_part_one", parsing_and_part_one),
+        &("parsing_and_part_two", parsing_and_part_two),
+        &("whole_solution", whole_solution),
+    ];
+    iai::runner(benchmarks);
+}
+"#;
+
+/// A new year's module, starting with the day being generated.
+const YEAR_TEMPLATE: &str = r#"//! Solutions for [Advent of Code __YEAR__](https://adventofcode.com/__YEAR__).
+
+use crate::DynSolution;
+
+pub mod day__PADDED__;
+
+/// All of this year's solutions, ordered by day.
+pub static SOLUTIONS: &[&dyn DynSolution] = &[&day__PADDED__::Day__PADDED__];
+"#;
+
+/// What to generate.
+#[derive(Clone, Debug)]
+pub struct NewDay {
+    pub day: i32,
+    pub year: i32,
+    pub title: String,
+}
+
+impl NewDay {
+    fn module(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    fn year_module(&self) -> String {
+        format!("y{}", self.year)
+    }
+
+    fn fill(&self, template: &str) -> String {
+        template
+            .replace("__YEAR__", &self.year.to_string())
+            .replace("__PADDED__", &format!("{:02}", self.day))
+            .replace("__DAY__", &self.day.to_string())
+            .replace("__TITLE__", &format!("{:?}", self.title))
+    }
+
+    pub fn day_source(&self) -> String {
+        self.fill(DAY_TEMPLATE)
+    }
+
+    pub fn bench_source(&self) -> String {
+        self.fill(BENCH_TEMPLATE)
+    }
+
+    /// The module for a year that doesn't have any solutions yet.
+    pub fn year_source(&self) -> String {
+        self.fill(YEAR_TEMPLATE)
+    }
+
+    /// Add `pub mod dayXX;` and the solution's `SOLUTIONS` entry to an existing year's module.
+    pub fn add_to_year(&self, year_module: &str) -> Result<String, ScaffoldError> {
+        let path = PathBuf::from(format!("src/{}/mod.rs", self.year_module()));
+        let year_module = add_line(
+            year_module,
+            &format!("pub mod {};", self.module()),
+            &path,
+            |existing| existing.starts_with("pub mod day"),
+        )?;
+        add_to_list(
+            &year_module,
+            "pub static SOLUTIONS",
+            "&day",
+            self.day,
+            |day| format!("&day{:02}::Day{:02}", day, day),
+            &path,
+        )
+    }
+
+    /// Add `pub mod yYYYY;` to `lib.rs`, keeping the modules in alphabetical order.
+    pub fn add_year_to_lib(&self, lib: &str) -> Result<String, ScaffoldError> {
+        add_line(
+            lib,
+            &format!("pub mod {};", self.year_module()),
+            Path::new("src/lib.rs"),
+            |existing| existing.starts_with("pub mod "),
+        )
+    }
+
+    /// Add the year to `YEARS` in `registry.rs`, keeping them in order.
+    pub fn add_year_to_registry(&self, registry: &str) -> Result<String, ScaffoldError> {
+        add_to_list(
+            registry,
+            "pub static YEARS",
+            "crate::y",
+            self.year,
+            |year| format!("({}, crate::y{}::SOLUTIONS)", year, year),
+            Path::new("src/registry.rs"),
+        )
+    }
+
+    /// Add a `[[bench]]` entry to `Cargo.toml`, keeping the benches in order.
+    pub fn add_bench_entry(&self, manifest: &str) -> Result<String, ScaffoldError> {
+        let entry = format!(
+            "[[bench]]\nname = \"{}_{}\"\npath = \"benches/{}/{}.rs\"\nharness = false\n",
+            self.year_module(),
+            self.module(),
+            self.year_module(),
+            self.module()
+        );
+        let mut blocks = manifest.split_inclusive("\n\n").collect::<Vec<_>>();
+        if blocks
+            .iter()
+            .any(|block| block.trim_end() == entry.trim_end())
+        {
+            return Err(ScaffoldError::AlreadyExists(PathBuf::from("Cargo.toml")));
+        }
+        let is_bench = |block: &&str| block.starts_with("[[bench]]");
+        let position = blocks
+            .iter()
+            .position(|block| is_bench(block) && *block > entry.as_str())
+            .or_else(|| blocks.iter().rposition(is_bench).map(|idx| idx + 1))
+            .unwrap_or(blocks.len());
+        let mut manifest = String::new();
+        if position == blocks.len() {
+            // Appending, so make sure there's a blank line before the new entry
+            manifest += &blocks.concat();
+            if !manifest.is_empty() && !manifest.ends_with("\n\n") {
+                manifest += if manifest.ends_with('\n') {
+                    "\n"
+                } else {
+                    "\n\n"
+                };
+            }
+            manifest += &entry;
+        } else {
+            let padded = entry + "\n";
+            blocks.insert(position, &padded);
+            manifest = blocks.concat();
+        }
+        Ok(manifest)
+    }
+
+    /// Write every file for the new day under `root`, the directory containing `Cargo.toml`.
+    /// The year's module is created too if this is its first day.
+    /// Nothing is written if any part of the day already exists.
+    pub fn generate(&self, root: &Path) -> Result<Vec<PathBuf>, ScaffoldError> {
+        let year_dir = root.join("src").join(self.year_module());
+        let day_path = year_dir.join(format!("{}.rs", self.module()));
+        let bench_path = root
+            .join("benches")
+            .join(self.year_module())
+            .join(format!("{}.rs", self.module()));
+        for path in [&day_path, &bench_path] {
+            if path.exists() {
+                return Err(ScaffoldError::AlreadyExists(path.clone()));
+            }
+        }
+        let read = |path: &Path| {
+            fs::read_to_string(path).map_err(|err| ScaffoldError::Io(path.to_path_buf(), err))
+        };
+        let year_path = year_dir.join("mod.rs");
+        let manifest_path = root.join("Cargo.toml");
+        // Work out every change before writing anything, so a failure leaves the tree untouched
+        let mut writes = vec![
+            (day_path, self.day_source()),
+            (bench_path, self.bench_source()),
+            (
+                manifest_path.clone(),
+                self.add_bench_entry(&read(&manifest_path)?)?,
+            ),
+        ];
+        if year_path.exists() {
+            writes.push((year_path.clone(), self.add_to_year(&read(&year_path)?)?));
+        } else {
+            let lib_path = root.join("src").join("lib.rs");
+            let registry_path = root.join("src").join("registry.rs");
+            writes.push((year_path, self.year_source()));
+            writes.push((lib_path.clone(), self.add_year_to_lib(&read(&lib_path)?)?));
+            writes.push((
+                registry_path.clone(),
+                self.add_year_to_registry(&read(&registry_path)?)?,
+            ));
+        }
+        let mut written = vec![];
+        for (path, contents) in writes {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|err| ScaffoldError::Io(path.clone(), err))?;
+            }
+            fs::write(&path, contents).map_err(|err| ScaffoldError::Io(path.clone(), err))?;
+            written.push(path);
+        }
+        Ok(written)
+    }
+}
+
+/// Add `new_line` to `contents` with [`insert_sorted`], failing if it's already there.
+fn add_line(
+    contents: &str,
+    new_line: &str,
+    path: &Path,
+    is_entry: impl Fn(&str) -> bool,
+) -> Result<String, ScaffoldError> {
+    if contents.lines().any(|existing| existing == new_line) {
+        return Err(ScaffoldError::AlreadyExists(path.into()));
+    }
+    insert_sorted(contents, new_line, is_entry)
+        .ok_or_else(|| ScaffoldError::NoPlaceFor(path.into()))
+}
+
+/// Add an entry to the array in the `static` starting with `declaration`, keeping it sorted.
+/// The numbers already in the list are found by looking for `marker`, and `entry` writes out the
+/// entry for each number. The array is written out afresh in the layout rustfmt would give it,
+/// as rustfmt moves between one line and one entry per line depending on the length.
+fn add_to_list(
+    contents: &str,
+    declaration: &str,
+    marker: &str,
+    new: i32,
+    entry: impl Fn(i32) -> String,
+    path: &Path,
+) -> Result<String, ScaffoldError> {
+    let no_place = || ScaffoldError::NoPlaceFor(path.into());
+    let start = contents.find(declaration).ok_or_else(no_place)?;
+    let end = start + contents[start..].find("];").ok_or_else(no_place)? + "];".len();
+    let list = &contents[start..end];
+    let (head, _) = list.split_once("= &[").ok_or_else(no_place)?;
+    let mut numbers = list
+        .match_indices(marker)
+        .filter_map(|(idx, marker)| {
+            let rest = &list[idx + marker.len()..];
+            let digits = rest.find(|c: char| !c.is_ascii_digit())?;
+            rest[..digits].parse::<i32>().ok()
+        })
+        .collect::<Vec<_>>();
+    if numbers.contains(&new) {
+        return Err(ScaffoldError::AlreadyExists(path.into()));
+    }
+    numbers.push(new);
+    numbers.sort();
+    let entries = numbers.into_iter().map(entry).collect::<Vec<_>>();
+    let one_line = format!("{}= &[{}];", head, entries.join(", "));
+    let replacement = if one_line.len() <= 100 {
+        one_line
+    } else {
+        format!("{}= &[\n    {},\n];", head, entries.join(",\n    "))
+    };
+    Ok(format!(
+        "{}{}{}",
+        &contents[..start],
+        replacement,
+        &contents[end..]
+    ))
+}
+
+/// Insert `new_line` among the consecutive lines matched by `is_entry`, before the first one that
+/// sorts after it. Returns `None` if there aren't any matching lines to put it with.
+fn insert_sorted(
+    contents: &str,
+    new_line: &str,
+    is_entry: impl Fn(&str) -> bool,
+) -> Option<String> {
+    let mut lines = contents.lines().collect::<Vec<_>>();
+    let first = lines.iter().position(|line| is_entry(line))?;
+    let last = first
+        + lines[first..]
+            .iter()
+            .take_while(|line| is_entry(line))
+            .count();
+    let position = (first..last)
+        .find(|&idx| lines[idx] > new_line)
+        .unwrap_or(last);
+    lines.insert(position, new_line);
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    Some(contents)
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// Some part of the day has already been created, the path says where.
+    AlreadyExists(PathBuf),
+    /// The file doesn't have the list the new day should be added to, it may need adding by hand.
+    NoPlaceFor(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl fmt::Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::AlreadyExists(path) => write!(
+                f,
+                "{} already has this day, not overwriting it",
+                path.display()
+            ),
+            ScaffoldError::NoPlaceFor(path) => write!(
+                f,
+                "Couldn't find where to add the day in {}, add it by hand",
+                path.display()
+            ),
+            ScaffoldError::Io(path, err) => {
+                write!(f, "Couldn't update {}: {}", path.display(), err)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScaffoldError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(day: i32) -> NewDay {
+        NewDay {
+            day,
+            year: 2023,
+            title: "Hot \"Springs\"".to_string(),
+        }
+    }
+
+    #[test]
+    fn check_templates_are_filled() {
+        let source = day(7).day_source();
+        assert!(source.starts_with("// https://adventofcode.com/2023/day/7\n"));
+        assert!(source.contains("impl Solution for Day07 {"));
+        assert!(source.contains("const DAY: i32 = 7;"));
+        assert!(source.contains(r#"const TITLE: &'static str = "Hot \"Springs\"";"#));
+        assert!(source.contains("fn check_day07_both_case1()"));
+        let bench = day(7).bench_source();
+        assert!(bench.contains("y2023::day07::Day07"));
+        assert!(bench.contains("load_input(2023, 7)"));
+        assert!(bench.contains("Lazy::force(&INPUT);\n    let benchmarks"));
+        assert!(!bench.contains("__"));
+        let year = day(7).year_source();
+        assert!(year.contains("pub mod day07;"));
+        assert!(year.contains("= &[&day07::Day07];"));
+    }
+
+    #[test]
+    fn check_day_is_added_to_year_in_order() {
+        let year_module = "use crate::DynSolution;\n\npub mod day01;\npub mod day03;\n\npub static SOLUTIONS: &[&dyn DynSolution] = &[\n    &day01::Day01,\n    &day03::Day03,\n];\n";
+        assert_eq!(
+            day(2).add_to_year(year_module).unwrap(),
+            "use crate::DynSolution;\n\npub mod day01;\npub mod day02;\npub mod day03;\n\npub static SOLUTIONS: &[&dyn DynSolution] = &[&day01::Day01, &day02::Day02, &day03::Day03];\n"
+        );
+        assert!(matches!(
+            day(3).add_to_year(year_module),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+        assert!(matches!(
+            day(3).add_to_year("use crate::DynSolution;\n"),
+            Err(ScaffoldError::NoPlaceFor(_))
+        ));
+        // A short list is kept on one line, as rustfmt would have it
+        assert!(day(2).add_to_year(&day(1).year_source()).unwrap().contains(
+            "pub static SOLUTIONS: &[&dyn DynSolution] = &[&day01::Day01, &day02::Day02];"
+        ));
+    }
+
+    #[test]
+    fn check_year_is_added_to_lib_and_registry() {
+        let lib = "pub mod bench;\npub mod scaffold;\npub mod y2022;\n";
+        assert_eq!(
+            day(1).add_year_to_lib(lib).unwrap(),
+            "pub mod bench;\npub mod scaffold;\npub mod y2022;\npub mod y2023;\n"
+        );
+        let registry = "use crate::DynSolution;\n\npub static YEARS: &[(i32, &[&dyn DynSolution])] = &[(2024, crate::y2024::SOLUTIONS)];\n\npub fn years() {}\n";
+        assert_eq!(
+            day(1).add_year_to_registry(registry).unwrap(),
+            "use crate::DynSolution;\n\npub static YEARS: &[(i32, &[&dyn DynSolution])] = &[\n    (2023, crate::y2023::SOLUTIONS),\n    (2024, crate::y2024::SOLUTIONS),\n];\n\npub fn years() {}\n"
+        );
+        assert!(matches!(
+            day(1).add_year_to_registry(&day(1).add_year_to_registry(registry).unwrap()),
+            Err(ScaffoldError::AlreadyExists(_))
+        ));
+    }
+
+    #[test]
+    fn check_bench_entry_is_inserted_in_order() {
+        let bench = |day: &str| {
+            format!(
+                "[[bench]]\nname = \"y2023_{}\"\npath = \"benches/y2023/{}.rs\"\nharness = false\n",
+                day, day
+            )
+        };
+        let manifest = format!(
+            "[package]\nname = \"aoc\"\n\n{}\n{}",
+            bench("day01"),
+            bench("day03")
+        );
+        assert_eq!(
+            day(2).add_bench_entry(&manifest).unwrap(),
+            format!(
+                "[package]\nname = \"aoc\"\n\n{}\n{}\n{}",
+                bench("day01"),
+                bench("day02"),
+                bench("day03")
+            )
+        );
+        assert_eq!(
+            day(4).add_bench_entry(&manifest).unwrap(),
+            format!("{}\n{}", manifest, bench("day04"))
+        );
+        assert!(day(3).add_bench_entry(&manifest).is_err());
+    }
+}
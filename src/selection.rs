@@ -0,0 +1,130 @@
+//! Choosing which days to run from the command line.
+
+use std::{fmt, ops::RangeInclusive, str::FromStr};
+
+/// Every day there can be a solution for, including the day 0 example.
+const DAYS: RangeInclusive<i32> = 0..=25;
+
+/// The days asked for on the command line, e.g. `5`, `3..7`, `3..=7`, `1,5,9` or `all`.
+/// Ranges follow Rust's syntax, so `3..7` doesn't include day 7 but `3..=7` does.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum DaySelection {
+    /// Every implemented day, skipping the day 0 example.
+    #[default]
+    All,
+    /// Specific days, in order and without repeats.
+    Days(Vec<i32>),
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(selection: &str) -> Result<Self, Self::Err> {
+        if selection.trim() == "all" {
+            return Ok(DaySelection::All);
+        }
+        let parse_day = |day: &str| {
+            let day = day.trim().parse::<i32>().map_err(|_| {
+                format!(
+                    "'{}' isn't a day, a range like 3..7 or a list like 1,5,9",
+                    selection
+                )
+            })?;
+            // Checked before any range is filled in, so a typo can't ask for billions of days
+            if !DAYS.contains(&day) {
+                return Err(format!(
+                    "There's no day {}, days go from {} to {}",
+                    day,
+                    DAYS.start(),
+                    DAYS.end()
+                ));
+            }
+            Ok(day)
+        };
+        let mut days = vec![];
+        for item in selection.split(',') {
+            if let Some((start, end)) = item.split_once("..=") {
+                days.extend(parse_day(start)?..=parse_day(end)?);
+            } else if let Some((start, end)) = item.split_once("..") {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start >= end {
+                    return Err(format!("{} doesn't include any days", item.trim()));
+                }
+                days.extend(start..end);
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+        if days.is_empty() {
+            return Err(format!("{} doesn't include any days", selection.trim()));
+        }
+        days.sort();
+        days.dedup();
+        Ok(DaySelection::Days(days))
+    }
+}
+
+/// A compact description of a set of days, with runs written as ranges, e.g. `1-3, 5, 7-25`.
+pub struct DayList<'a>(pub &'a [i32]);
+
+impl fmt::Display for DayList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut days = self.0.to_vec();
+        days.sort();
+        days.dedup();
+        let mut runs: Vec<(i32, i32)> = vec![];
+        for day in days {
+            match runs.last_mut() {
+                Some((_, end)) if *end + 1 == day => *end = day,
+                _ => runs.push((day, day)),
+            }
+        }
+        let runs = runs
+            .into_iter()
+            .map(|(start, end)| {
+                if start == end {
+                    start.to_string()
+                } else {
+                    format!("{}-{}", start, end)
+                }
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{}", runs.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_day_selection_parse() {
+        let days = |days: &[i32]| Ok(DaySelection::Days(days.to_vec()));
+        assert_eq!("all".parse(), Ok(DaySelection::All));
+        assert_eq!("5".parse(), days(&[5]));
+        assert_eq!("3..7".parse(), days(&[3, 4, 5, 6]));
+        assert_eq!("3..=7".parse(), days(&[3, 4, 5, 6, 7]));
+        assert_eq!("9, 1,5".parse(), days(&[1, 5, 9]));
+        assert_eq!("1..3,2,10".parse(), days(&[1, 2, 10]));
+    }
+
+    #[test]
+    fn check_bad_day_selection() {
+        assert!("five".parse::<DaySelection>().is_err());
+        assert!("3..".parse::<DaySelection>().is_err());
+        assert!("7..3".parse::<DaySelection>().is_err());
+        assert!("7..=3".parse::<DaySelection>().is_err());
+        assert_eq!(
+            "1..=2000000000".parse::<DaySelection>(),
+            Err("There's no day 2000000000, days go from 0 to 25".to_string())
+        );
+        assert!("-1".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn check_day_list() {
+        assert_eq!(DayList(&[0, 1, 2, 3, 5, 7, 8]).to_string(), "0-3, 5, 7-8");
+        assert_eq!(DayList(&[4]).to_string(), "4");
+        assert_eq!(DayList(&[]).to_string(), "");
+    }
+}
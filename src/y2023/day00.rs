@@ -10,51 +10,69 @@
 // and pass that through as the input to the Day00 solution here as a single &str.
 // This solution (as with all DayXX templates in this repo) returns two Strings, which will be printed
 // out to terminal following the labels "Part 1:" and "Part 2: respectively".
+// If the input isn't what's expected, return an AocError instead of panicking. The runner will report
+// where it went wrong and carry on with the other days.
 // You can also run with `--bench` to benchmark the different parts of the solution.
 
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Solution,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day00;
 
 impl Solution for Day00 {
+    const DAY: i32 = 0;
+    const TITLE: &'static str = "Example";
+
     type ParsedInput = String;
 
-    fn parse_input(input_lines: &str) -> Self::ParsedInput {
-        input_lines.to_string()
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        Ok(input_lines.to_string())
     }
 
-    fn part_one(parsed_input: &mut Self::ParsedInput) -> String {
-        format!(
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(format!(
             "{}",
-            parsed_input.lines().map(sum_numbers_in_line).sum::<i32>()
-        )
+            parsed_input
+                .lines()
+                .enumerate()
+                .map(|(i, line)| sum_numbers_in_line(line).map_err(|err| err.at_line(i + 1)))
+                .sum::<AocResult<i32>>()?
+        ))
     }
 
-    fn part_two(parsed_input: &mut Self::ParsedInput) -> String {
-        format!(
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(format!(
             "{}",
             parsed_input
                 .lines()
-                .map(square_difference_in_line)
-                .sum::<i32>()
-        )
+                .enumerate()
+                .map(|(i, line)| square_difference_in_line(line).map_err(|err| err.at_line(i + 1)))
+                .sum::<AocResult<i32>>()?
+        ))
     }
 }
 
-fn sum_numbers_in_line(line: &str) -> i32 {
+fn sum_numbers_in_line(line: &str) -> AocResult<i32> {
     line.split(", ")
-        .map(|number| number.parse::<i32>().expect("Couldn't parse"))
-        .sum::<i32>()
+        .map(|number| Ok(number.parse::<i32>()?))
+        .sum::<AocResult<i32>>()
 }
 
-fn square_difference_in_line(line: &str) -> i32 {
+fn square_difference_in_line(line: &str) -> AocResult<i32> {
     let numbers = line
         .split(", ")
-        .map(|number| number.parse::<i32>().expect("Couldn't parse"))
-        .collect::<Vec<i32>>();
-    assert_eq!(numbers.len(), 2);
-    (numbers.first().unwrap() - numbers.get(1).unwrap()).pow(2)
+        .map(|number| number.parse::<i32>())
+        .collect::<Result<Vec<i32>, _>>()?;
+    match numbers[..] {
+        [a, b] => Ok((a - b).pow(2)),
+        _ => Err(AocError::new(format!(
+            "Expected 2 numbers, found {}",
+            numbers.len()
+        ))),
+    }
 }
 
 // The template per-day files also come with template UTs.  Most Advent of Code puzzles
@@ -95,8 +113,7 @@ mod tests {
         assert_eq!(
             Day00::solve(
                 "1, 2
-40, 30",
-                false
+40, 30"
             ),
             ("73".to_string(), "101".to_string())
         )
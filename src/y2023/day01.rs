@@ -1,6 +1,9 @@
 use std::collections::VecDeque;
 
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Solution,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day01;
@@ -19,19 +22,22 @@ const NINE: &str = "nine";
 const WORD_NUMS: [&str; 9] = [ONE, TWO, THREE, FOUR, FIVE, SIX, SEVEN, EIGHT, NINE];
 
 impl Solution for Day01 {
+    const DAY: i32 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
     type ParsedInput = String;
 
-    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         // Change the return type of this function by editing the ParsedInput type above.
         // You can skip this and pass the raw string to each part.
         // Alternatively, you can parse the input here, either working on the same mutable struct
         // in parts one and two or passing a tuple with the data required for each part.
-        input_lines.to_string()
+        Ok(input_lines.to_string())
     }
 
-    fn part_one(parsed_input: &mut Self::ParsedInput) -> String {
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
         let mut total = 0;
-        for line in parsed_input.lines() {
+        for (i, line) in parsed_input.lines().enumerate() {
             let (mut first, mut last) = (None, None);
             for val in line.chars() {
                 if NUMS.contains(val) {
@@ -42,18 +48,21 @@ impl Solution for Day01 {
                 }
             }
 
-            let f = first.expect("Line must contain digit").to_string();
-            let l = last.expect("Line must contain digit").to_string();
-            let value_str = [f, l].join("");
+            let (Some(f), Some(l)) = (first, last) else {
+                return Err(AocError::new("Line must contain digit").at_line(i + 1));
+            };
+            let value_str = [f, l].iter().collect::<String>();
 
-            total += value_str.parse::<u32>().expect("Value must be integer");
+            total += value_str
+                .parse::<u32>()
+                .map_err(|err| AocError::from(err).at_line(i + 1))?;
         }
-        total.to_string()
+        Ok(total.to_string())
     }
 
-    fn part_two(parsed_input: &mut Self::ParsedInput) -> String {
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
         let mut total = 0;
-        for line in parsed_input.lines() {
+        for (i, line) in parsed_input.lines().enumerate() {
             let (mut first, mut last) = (None, None);
             let mut recent_state = VecDeque::new();
             for val in line.chars() {
@@ -72,15 +81,18 @@ impl Solution for Day01 {
                 }
             }
 
-            let f = first.expect("Line must contain digit").to_string();
-            let l = last.expect("Line must contain digit").to_string();
-            let value_str = [f, l].join("");
+            let (Some(f), Some(l)) = (first, last) else {
+                return Err(AocError::new("Line must contain digit").at_line(i + 1));
+            };
+            let value_str = [f, l].iter().collect::<String>();
 
             // println!("{} - {}", value_str, line);
 
-            total += value_str.parse::<u32>().expect("Value must be integer");
+            total += value_str
+                .parse::<u32>()
+                .map_err(|err| AocError::from(err).at_line(i + 1))?;
         }
-        total.to_string()
+        Ok(total.to_string())
     }
 }
 
@@ -95,13 +107,7 @@ fn check_recent_state_for_number(state: &VecDeque<char>) -> Option<char> {
                 }
             }
             if all_matching {
-                return Some(
-                    value
-                        .to_string()
-                        .chars()
-                        .next()
-                        .expect("Should have one character in this int"),
-                );
+                return char::from_digit(value, 10);
             }
         }
     }
@@ -161,6 +167,6 @@ hlqphphn5dtzzbqvk3three8seven"
 
     #[test]
     fn check_day01_both_case1() {
-        assert_eq!(Day01::solve("", false), ("0".to_string(), "0".to_string()))
+        assert_eq!(Day01::solve(""), ("0".to_string(), "0".to_string()))
     }
 }
@@ -0,0 +1,133 @@
+use std::{cmp::max, str::FromStr};
+
+use crate::{
+    error::{AocError, AocResult},
+    parser::Parser,
+    Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: i32 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type ParsedInput = Vec<Game>;
+
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        // Change the return type of this function by editing the ParsedInput type above.
+        // You can skip this and pass the raw string to each part.
+        // Alternatively, you can parse the input here, either working on the same mutable struct
+        // in parts one and two or passing a tuple with the data required for each part.
+        let mut games = vec![];
+        for (i, line) in input_lines.lines().enumerate() {
+            let game = Game::from_str(line).map_err(|err| err.at_line(i + 1))?;
+            games.push(game);
+        }
+        Ok(games)
+    }
+
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(parsed_input
+            .iter()
+            .filter_map(|game| {
+                if game.colour_max.0 <= 12 && game.colour_max.1 <= 13 && game.colour_max.2 <= 14 {
+                    Some(game.id)
+                } else {
+                    None
+                }
+            })
+            .sum::<u32>()
+            .to_string())
+    }
+
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(parsed_input
+            .iter()
+            .map(|game| game.colour_max.0 * game.colour_max.1 * game.colour_max.2)
+            .sum::<u32>()
+            .to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct Game {
+    id: u32,
+    colour_max: (u32, u32, u32),
+}
+
+impl FromStr for Game {
+    fn from_str(s: &str) -> Result<Game, AocError> {
+        let mut colour_max = (0, 0, 0);
+
+        let mut parser = Parser::new(s);
+        parser.expect_literal("Game")?;
+        let id = parser.parse_int()?;
+        parser.expect_literal(":")?;
+
+        // Each turn is separated by ';' and each colour by ',', but only the maximums matter
+        loop {
+            let quantity = parser.parse_int()?;
+            parser.skip_whitespace();
+            let column = parser.column();
+            let colour = parser
+                .parse_word()
+                .map_err(|_| parser.error("Expected a colour"))?;
+
+            match colour {
+                "red" => {
+                    colour_max.0 = max(colour_max.0, quantity);
+                }
+                "green" => {
+                    colour_max.1 = max(colour_max.1, quantity);
+                }
+                "blue" => {
+                    colour_max.2 = max(colour_max.2, quantity);
+                }
+                other => {
+                    return Err(parser.error_at(column, format!("Unexpected colour '{}'", other)))
+                }
+            }
+            if !(parser.step_over(',') || parser.step_over(';')) {
+                break;
+            }
+        }
+        if !parser.is_done() {
+            return Err(parser.error("Expected ',' or ';'"));
+        }
+
+        Ok(Game { id, colour_max })
+    }
+
+    type Err = AocError;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_day02_part1_case1() {
+        assert_eq!(
+            Day02::solve_part_one(
+                "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
+            ),
+            "8".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day02_part2_case1() {
+        assert_eq!(Day02::solve_part_two(""), "0".to_string())
+    }
+
+    #[test]
+    fn check_day02_both_case1() {
+        assert_eq!(Day02::solve(""), ("0".to_string(), "0".to_string()))
+    }
+}
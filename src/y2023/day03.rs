@@ -2,30 +2,24 @@ use std::collections::HashMap;
 
 use petgraph::{Graph, Undirected};
 
-use crate::Solution;
+use crate::{error::AocResult, grid::Grid, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day03;
 
 impl Solution for Day03 {
+    const DAY: i32 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
     type ParsedInput = Graph<Node, (), Undirected>;
 
-    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         // Change the return type of this function by editing the ParsedInput type above.
         // You can skip this and pass the raw string to each part.
         // Alternatively, you can parse the input here, either working on the same mutable struct
         // in parts one and two or passing a tuple with the data required for each part.
 
-        const YSIZE: usize = 140;
-        const XSIZE: usize = 140;
-
-        let mut grid = [['.'; XSIZE]; YSIZE];
-
-        for (i_y, line) in input_lines.lines().enumerate() {
-            for (i_x, character) in line.chars().enumerate() {
-                grid[i_y][i_x] = character;
-            }
-        }
+        let grid: Grid<char> = input_lines.parse()?;
 
         let mut graph = Graph::<Node, (), Undirected>::new_undirected();
         let mut symbol_indexes = HashMap::new();
@@ -35,20 +29,17 @@ impl Solution for Day03 {
         let mut adjacents = Vec::new(); // For collecting adjacent characters and their coords
         let mut adjacent_node_idxs = Vec::new(); // For collecting the graph node indices of adjacent characters
 
-        for (i_y, row) in grid.iter().enumerate() {
+        for (i_y, row) in grid.rows().enumerate() {
             let mut value = 0;
             adjacents.clear();
             for (i_x, character) in row.iter().enumerate() {
                 if character.is_ascii_digit() {
                     value += character.to_digit(10).unwrap();
 
-                    for idx in adjacent_idxs((i_x, i_y)) {
-                        if let Some(row) = grid.get(idx.1) {
-                            if let Some(character) = row.get(idx.0) {
-                                if *character != '.' && !character.is_ascii_digit() {
-                                    adjacents.push((character, idx));
-                                }
-                            }
+                    for idx in grid.neighbours8((i_x, i_y)) {
+                        let character = &grid[idx];
+                        if *character != '.' && !character.is_ascii_digit() {
+                            adjacents.push((character, idx));
                         }
                     }
 
@@ -85,11 +76,11 @@ impl Solution for Day03 {
             }
         }
 
-        graph
+        Ok(graph)
     }
 
-    fn part_one(parsed_input: &mut Self::ParsedInput) -> String {
-        parsed_input
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(parsed_input
             .node_indices()
             .filter_map(|node_idx| {
                 if parsed_input[node_idx].is_num() && parsed_input.neighbors(node_idx).count() != 0
@@ -100,11 +91,11 @@ impl Solution for Day03 {
                 }
             })
             .sum::<u32>()
-            .to_string()
+            .to_string())
     }
 
-    fn part_two(parsed_input: &mut Self::ParsedInput) -> String {
-        parsed_input
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(parsed_input
             .node_indices()
             .filter(|node_idx| parsed_input[*node_idx].is_star())
             .filter_map(|node_idx| {
@@ -131,31 +122,11 @@ impl Solution for Day03 {
                 }
             })
             .sum::<u32>()
-            .to_string()
+            .to_string())
     }
 }
 
-fn adjacent_idxs(coord: (usize, usize)) -> Vec<(usize, usize)> {
-    let mut idxs = vec![
-        (coord.0 + 1, coord.1 + 1),
-        (coord.0 + 1, coord.1),
-        (coord.0, coord.1 + 1),
-    ];
-    if coord.0 > 0 {
-        idxs.push((coord.0 - 1, coord.1 + 1));
-        idxs.push((coord.0 - 1, coord.1));
-        if coord.1 > 0 {
-            idxs.push((coord.0 - 1, coord.1 - 1));
-        }
-    }
-    if coord.1 > 0 {
-        idxs.push((coord.0 + 1, coord.1 - 1));
-        idxs.push((coord.0, coord.1 - 1));
-    }
-    idxs
-}
-
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Node {
     number: Option<u32>,
     symbol: Option<char>,
@@ -220,6 +191,6 @@ mod tests {
 
     #[test]
     fn check_day03_both_case1() {
-        assert_eq!(Day03::solve("", false), ("0".to_string(), "0".to_string()))
+        assert_eq!(Day03::solve(""), ("0".to_string(), "0".to_string()))
     }
 }
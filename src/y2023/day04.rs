@@ -0,0 +1,107 @@
+use crate::{error::AocResult, parser, Solution};
+
+#[derive(Clone, Debug)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: i32 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type ParsedInput = Vec<Card>;
+
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        // Change the return type of this function by editing the ParsedInput type above.
+        // You can skip this and pass the raw string to each part.
+        // Alternatively, you can parse the input here, either working on the same mutable struct
+        // in parts one and two or passing a tuple with the data required for each part.
+
+        let mut cards = Vec::new();
+
+        for mut parser in parser::lines(input_lines) {
+            parser.expect_literal("Card")?;
+            let _id: u32 = parser.parse_int()?;
+            parser.expect_literal(":")?;
+
+            let winning_numbers = parser.parse_separated(' ', |parser| parser.parse_int::<u8>())?;
+
+            parser.expect_literal("|")?;
+
+            let my_numbers = parser.parse_separated(' ', |parser| parser.parse_int::<u8>())?;
+            if !parser.is_done() {
+                return Err(parser.error("Expected a number"));
+            }
+
+            cards.push(Card {
+                winners: my_numbers
+                    .into_iter()
+                    .filter(|number| winning_numbers.contains(number))
+                    .collect(),
+            })
+        }
+
+        Ok(cards)
+    }
+
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(parsed_input
+            .iter()
+            .map(|card| {
+                if !card.winners.is_empty() {
+                    1 << (card.winners.len() - 1)
+                } else {
+                    0
+                }
+            })
+            .sum::<u32>()
+            .to_string())
+    }
+
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        let mut additional_scratchcards = vec![0; parsed_input.len()];
+        for (i, card) in parsed_input.iter().enumerate() {
+            for _ in 0..(additional_scratchcards[i] + 1) {
+                for j in 0..card.winners.len() {
+                    if let Some(value) = additional_scratchcards.get_mut(i + j + 1) {
+                        *value += 1;
+                    }
+                }
+            }
+        }
+        Ok((additional_scratchcards.iter().sum::<usize>() + parsed_input.len()).to_string())
+    }
+}
+
+#[derive(Clone)]
+pub struct Card {
+    winners: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_day04_part1_case1() {
+        assert_eq!(
+            Day04::solve_part_one(
+                "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11"
+            ),
+            "13".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day04_part2_case1() {
+        assert_eq!(Day04::solve_part_two(""), "0".to_string())
+    }
+
+    #[test]
+    fn check_day04_both_case1() {
+        assert_eq!(Day04::solve(""), ("0".to_string(), "0".to_string()))
+    }
+}
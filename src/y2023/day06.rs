@@ -1,12 +1,18 @@
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Solution,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day06;
 
 impl Solution for Day06 {
+    const DAY: i32 = 6;
+    const TITLE: &'static str = "Wait For It";
+
     type ParsedInput = (Vec<(u16, u16)>, (u64, u64));
 
-    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         // Change the return type of this function by editing the ParsedInput type above.
         // You can skip this and pass the raw string to each part.
         // Alternatively, you can parse the input here, either working on the same mutable struct
@@ -14,26 +20,27 @@ impl Solution for Day06 {
         let mut p2_dist = "".to_string();
         let mut p2_time = "".to_string();
         let mut lines = input_lines.lines();
-        let p1_data = lines
+        let times = lines
+            .next()
+            .ok_or_else(|| AocError::new("Missing race times").at_line(1))?;
+        let distances = lines
             .next()
-            .unwrap()
+            .ok_or_else(|| AocError::new("Missing race distances").at_line(2))?;
+        let p1_data = times
             .split_ascii_whitespace()
-            .zip(lines.next().unwrap().split_ascii_whitespace())
+            .zip(distances.split_ascii_whitespace())
             .skip(1)
             .map(|(time, dist)| {
                 p2_dist += dist;
                 p2_time += time;
-                (time.parse::<u16>().unwrap(), dist.parse::<u16>().unwrap())
+                Ok((time.parse::<u16>()?, dist.parse::<u16>()?))
             })
-            .collect::<Vec<(u16, u16)>>();
-        (
-            p1_data,
-            (p2_time.parse().unwrap(), p2_dist.parse().unwrap()),
-        )
+            .collect::<AocResult<Vec<(u16, u16)>>>()?;
+        Ok((p1_data, (p2_time.parse()?, p2_dist.parse()?)))
     }
 
-    fn part_one(parsed_input: &mut Self::ParsedInput) -> String {
-        parsed_input
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(parsed_input
             .0
             .iter()
             .map(|(time, dist)| {
@@ -45,19 +52,19 @@ impl Solution for Day06 {
                     .count() as u64
             })
             .product::<u64>()
-            .to_string()
+            .to_string())
     }
 
-    fn part_two(parsed_input: &mut Self::ParsedInput) -> String {
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
         let time = parsed_input.1 .0;
         let dist = parsed_input.1 .1;
-        (0..time)
+        Ok((0..time)
             .filter(move |i| {
                 let time_remaining = time - i;
                 time_remaining * i > dist
             })
             .count()
-            .to_string()
+            .to_string())
     }
 }
 
@@ -77,6 +84,6 @@ mod tests {
 
     #[test]
     fn check_day06_both_case1() {
-        assert_eq!(Day06::solve("", false), ("0".to_string(), "0".to_string()))
+        assert_eq!(Day06::solve(""), ("0".to_string(), "0".to_string()))
     }
 }
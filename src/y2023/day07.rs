@@ -2,15 +2,21 @@ use std::{cmp, slice::Iter};
 
 use counter::Counter;
 
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Solution,
+};
 
 #[derive(Clone, Debug)]
 pub struct Day07;
 
 impl Solution for Day07 {
+    const DAY: i32 = 7;
+    const TITLE: &'static str = "Camel Cards";
+
     type ParsedInput = (Vec<Hand>, Vec<Hand>);
 
-    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         // Change the return type of this function by editing the ParsedInput type above.
         // You can skip this and pass the raw string to each part.
         // Alternatively, you can parse the input here, either working on the same mutable struct
@@ -20,27 +26,36 @@ impl Solution for Day07 {
         let mut counter2 = Counter::new();
         let hands = input_lines
             .lines()
-            .map(|line| {
+            .enumerate()
+            .map(|(i, line)| {
                 let mut parts = line.split_ascii_whitespace();
-                let hand = parts.next().unwrap();
-                let bid = parts.next().unwrap().parse::<u16>().unwrap();
-                joker_hands.push(Hand::new_jokers(hand, bid, &mut counter2));
-                Hand::new(hand, bid, &mut counter)
+                let hand = parts
+                    .next()
+                    .ok_or_else(|| AocError::new("Missing hand").at_line(i + 1))?;
+                let bid = parts
+                    .next()
+                    .ok_or_else(|| AocError::new("Missing bid").at_line(i + 1))?
+                    .parse::<u16>()
+                    .map_err(|err| AocError::from(err).at_line(i + 1))?;
+                joker_hands.push(
+                    Hand::new_jokers(hand, bid, &mut counter2).map_err(|err| err.at_line(i + 1))?,
+                );
+                Hand::new(hand, bid, &mut counter).map_err(|err| err.at_line(i + 1))
             })
-            .collect::<Vec<_>>();
-        (hands, joker_hands)
+            .collect::<AocResult<Vec<_>>>()?;
+        Ok((hands, joker_hands))
     }
 
-    fn part_one(parsed_input: &mut Self::ParsedInput) -> String {
-        solve(&mut parsed_input.0)
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(solve(&mut parsed_input.0))
     }
 
-    fn part_two(parsed_input: &mut Self::ParsedInput) -> String {
-        solve(&mut parsed_input.1)
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(solve(&mut parsed_input.1))
     }
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct Hand {
     cards: [Card; 5],
     bid: u16,
@@ -48,19 +63,26 @@ pub struct Hand {
 }
 
 impl Hand {
-    fn new(hand_str: &str, bid: u16, counter: &mut Counter<char, usize>) -> Self {
-        let mut cards = hand_str.chars().map(|c| Card::from_char(&c).unwrap());
-        Hand {
-            cards: [
-                cards.next().unwrap(),
-                cards.next().unwrap(),
-                cards.next().unwrap(),
-                cards.next().unwrap(),
-                cards.next().unwrap(),
-            ],
+    fn new(hand_str: &str, bid: u16, counter: &mut Counter<char, usize>) -> AocResult<Self> {
+        Ok(Hand {
+            cards: Hand::parse_cards(hand_str, Card::from_char)?,
             bid,
             hand_type: Hand::hand_type(hand_str, counter),
-        }
+        })
+    }
+
+    fn parse_cards(
+        hand_str: &str,
+        from_char: fn(&char) -> AocResult<Card>,
+    ) -> AocResult<[Card; 5]> {
+        let cards = hand_str
+            .chars()
+            .enumerate()
+            .map(|(i, c)| from_char(&c).map_err(|err| err.at_column(i + 1)))
+            .collect::<AocResult<Vec<_>>>()?;
+        cards.try_into().map_err(|cards: Vec<Card>| {
+            AocError::new(format!("Expected 5 cards in a hand, found {}", cards.len()))
+        })
     }
 
     fn hand_type(hand_str: &str, counter: &mut Counter<char, usize>) -> HandType {
@@ -71,21 +93,12 @@ impl Hand {
         HandType::from_count(count_iter.next().unwrap().1, &mut count_iter)
     }
 
-    fn new_jokers(hand_str: &str, bid: u16, counter: &mut Counter<char, usize>) -> Self {
-        let mut cards = hand_str
-            .chars()
-            .map(|c| Card::from_char_jokers(&c).unwrap());
-        Hand {
-            cards: [
-                cards.next().unwrap(),
-                cards.next().unwrap(),
-                cards.next().unwrap(),
-                cards.next().unwrap(),
-                cards.next().unwrap(),
-            ],
+    fn new_jokers(hand_str: &str, bid: u16, counter: &mut Counter<char, usize>) -> AocResult<Self> {
+        Ok(Hand {
+            cards: Hand::parse_cards(hand_str, Card::from_char_jokers)?,
             bid,
             hand_type: Hand::hand_type_jokers(hand_str, counter),
-        }
+        })
     }
 
     fn hand_type_jokers(hand_str: &str, counter: &mut Counter<char, usize>) -> HandType {
@@ -101,7 +114,7 @@ impl Hand {
     }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 enum Card {
     Ace,
     King,
@@ -121,7 +134,7 @@ enum Card {
 }
 
 impl Card {
-    fn from_char(c: &char) -> Result<Self, ()> {
+    fn from_char(c: &char) -> AocResult<Self> {
         match c {
             'A' => Ok(Self::Ace),
             'K' => Ok(Self::King),
@@ -137,11 +150,11 @@ impl Card {
             '3' => Ok(Self::Three),
             '2' => Ok(Self::Two),
             '1' => Ok(Self::One),
-            _ => Err(()),
+            _ => Err(AocError::new(format!("Unexpected card '{}'", c))),
         }
     }
 
-    fn from_char_jokers(c: &char) -> Result<Self, ()> {
+    fn from_char_jokers(c: &char) -> AocResult<Self> {
         match c {
             'A' => Ok(Self::Ace),
             'K' => Ok(Self::King),
@@ -157,12 +170,12 @@ impl Card {
             '2' => Ok(Self::Two),
             '1' => Ok(Self::One),
             'J' => Ok(Self::Joker),
-            _ => Err(()),
+            _ => Err(AocError::new(format!("Unexpected card '{}'", c))),
         }
     }
 }
 
-#[derive(PartialEq, Eq, PartialOrd, Ord, Debug)]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
 enum HandType {
     FiveOfAKind,
     FourOfAKind,
@@ -255,6 +268,6 @@ QQQJA 483"
 
     #[test]
     fn check_day07_both_case1() {
-        assert_eq!(Day07::solve("", false), ("0".to_string(), "0".to_string()))
+        assert_eq!(Day07::solve(""), ("0".to_string(), "0".to_string()))
     }
 }
@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+
+use num::Integer;
+use petgraph::{stable_graph::NodeIndex, visit::EdgeRef, Directed, Graph};
+
+use crate::{
+    error::{AocError, AocResult},
+    parser::Parser,
+    point::Dir4,
+    Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: i32 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type ParsedInput = (Vec<Dir4>, Graph<String, Dir4, Directed>, NodeIndex);
+
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        // Change the return type of this function by editing the ParsedInput type above.
+        // You can skip this and pass the raw string to each part.
+        // Alternatively, you can parse the input here, either working on the same mutable struct
+        // in parts one and two or passing a tuple with the data required for each part.
+        let mut lines = input_lines.lines();
+        let directions = lines
+            .next()
+            .ok_or_else(|| AocError::new("Missing directions").at_line(1))?
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                'L' => Ok(Dir4::West),
+                'R' => Ok(Dir4::East),
+                _ => Err(AocError::new(format!("Unexpected direction '{}'", c)).at(1, i + 1)),
+            })
+            .collect::<AocResult<Vec<_>>>()?;
+        if directions.is_empty() {
+            return Err(AocError::new("Missing directions").at_line(1));
+        }
+
+        let mut graph = Graph::<String, Dir4, Directed>::new();
+        let mut node_indices = HashMap::new();
+
+        let mut starting_index = None;
+        lines.next();
+
+        // Node lines start after the directions and a blank line
+        for (line_idx, line) in lines.enumerate() {
+            let mut parser = Parser::new(line).on_line(line_idx + 3);
+            let node = parse_node(&mut parser)?;
+            parser.expect_literal("=")?;
+            parser.expect_literal("(")?;
+            let left = parse_node(&mut parser)?;
+            parser.expect_literal(",")?;
+            let right = parse_node(&mut parser)?;
+            parser.expect_literal(")")?;
+
+            let node_idx = *node_indices
+                .entry(node.clone())
+                .or_insert(graph.add_node(node.clone()));
+            let left_idx = *node_indices
+                .entry(left.clone())
+                .or_insert(graph.add_node(left));
+            let right_idx = *node_indices
+                .entry(right.clone())
+                .or_insert(graph.add_node(right));
+
+            graph.add_edge(node_idx, left_idx, Dir4::West);
+            graph.add_edge(node_idx, right_idx, Dir4::East);
+            if node == "AAA" {
+                starting_index = Some(node_idx);
+            }
+        }
+
+        let starting_index =
+            starting_index.ok_or_else(|| AocError::new("No starting node 'AAA' in the map"))?;
+        Ok((directions, graph, starting_index))
+    }
+
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        let directions = &parsed_input.0;
+        let graph = &parsed_input.1;
+        let mut count = 0;
+        let mut current_node_idx = parsed_input.2;
+        for direction in directions.iter().cycle() {
+            count += 1;
+            current_node_idx = step(graph, current_node_idx, direction)?;
+            if graph[current_node_idx] == "ZZZ" {
+                break;
+            }
+        }
+        Ok(count.to_string())
+    }
+
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        let directions = &parsed_input.0;
+        let graph = &parsed_input.1;
+
+        let mut nodes = graph
+            .node_indices()
+            .filter(|node| graph[*node].ends_with('A'))
+            .collect::<Vec<_>>();
+        if nodes.is_empty() {
+            return Err(AocError::new("No starting nodes ending in 'A' in the map"));
+        }
+        let mut count: u64 = 0;
+
+        let mut step_counts = vec![None; nodes.len()];
+
+        for direction in directions.iter().cycle() {
+            count += 1;
+
+            for (i, current_node_idx) in nodes.iter_mut().enumerate() {
+                *current_node_idx = step(graph, *current_node_idx, direction)?;
+                if graph[*current_node_idx].ends_with('Z') {
+                    step_counts[i] = Some(count);
+                }
+            }
+            if step_counts.iter().all(|opt| opt.is_some()) {
+                break;
+            }
+        }
+        Ok(step_counts
+            .into_iter()
+            .flatten()
+            .fold(1, |a, b| a.lcm(&b))
+            .to_string())
+    }
+}
+
+/// Follow the edge from `node` in `direction`, which every node defined in the map has.
+fn step(
+    graph: &Graph<String, Dir4, Directed>,
+    node: NodeIndex,
+    direction: &Dir4,
+) -> AocResult<NodeIndex> {
+    graph
+        .edges(node)
+        .find(|edge| edge.weight() == direction)
+        .map(|edge| edge.target())
+        .ok_or_else(|| AocError::new(format!("Node '{}' isn't defined in the map", graph[node])))
+}
+
+fn parse_node(parser: &mut Parser) -> AocResult<String> {
+    parser
+        .parse_word()
+        .map(str::to_string)
+        .map_err(|_| parser.error("Expected a node name"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_day08_part1_case1() {
+        assert_eq!(
+            Day08::solve_part_one(
+                "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)"
+            ),
+            "2".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day08_part1_case2() {
+        assert_eq!(
+            Day08::solve_part_one(
+                "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)"
+            ),
+            "2".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day08_only_left_and_right() {
+        assert_eq!(
+            Day08::parse_input("LRW\n\nAAA = (ZZZ, ZZZ)")
+                .unwrap_err()
+                .to_string(),
+            "line 1:3: Unexpected direction 'W'"
+        )
+    }
+
+    #[test]
+    fn check_day08_part2_case1() {
+        assert_eq!(Day08::solve_part_two(""), "0".to_string())
+    }
+
+    #[test]
+    fn check_day08_both_case1() {
+        assert_eq!(Day08::solve(""), ("0".to_string(), "0".to_string()))
+    }
+}
@@ -1,6 +1,9 @@
 // use std::ops::Mul;
 
-use crate::Solution;
+use crate::{
+    error::{AocError, AocResult},
+    Solution,
+};
 
 // const N: usize = if cfg!(test) { 6 } else { 21 };
 
@@ -14,24 +17,34 @@ use crate::Solution;
 pub struct Day09;
 
 impl Solution for Day09 {
+    const DAY: i32 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
     type ParsedInput = Vec<Vec<i64>>;
 
-    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         // Change the return type of this function by editing the ParsedInput type above.
         // You can skip this and pass the raw string to each part.
         // Alternatively, you can parse the input here, either working on the same mutable struct
         // in parts one and two or passing a tuple with the data required for each part.
         input_lines
             .lines()
-            .map(|line| {
-                line.split_ascii_whitespace()
-                    .map(|num| num.parse::<i64>().unwrap())
-                    .collect::<Vec<_>>()
+            .enumerate()
+            .map(|(i, line)| {
+                let sequence = line
+                    .split_ascii_whitespace()
+                    .map(|num| num.parse::<i64>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|err| AocError::from(err).at_line(i + 1))?;
+                if sequence.is_empty() {
+                    return Err(AocError::new("Expected a sequence of numbers").at_line(i + 1));
+                }
+                Ok(sequence)
             })
-            .collect::<Vec<_>>()
+            .collect::<AocResult<Vec<_>>>()
     }
 
-    fn part_one(parsed_input: &mut Self::ParsedInput) -> String {
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
         // I had a rather lovely solution for the example data for part 1
         // I've included it commented below
         // Solved a system of linear equations to find the coefficients for
@@ -66,6 +79,7 @@ impl Solution for Day09 {
         parsed_input
             .iter()
             .map(|seq| {
+                let last = *seq.last().ok_or_else(|| AocError::new("Empty sequence"))?;
                 let mut levels = vec![];
                 let mut current_seq = seq.iter().rev().copied().collect::<Vec<_>>();
                 loop {
@@ -86,16 +100,17 @@ impl Solution for Day09 {
                         levels.push(diffs);
                     }
                 }
-                levels.iter().map(|level| level[0]).sum::<i64>() + *seq.iter().last().unwrap()
+                Ok(levels.iter().filter_map(|level| level.first()).sum::<i64>() + last)
             })
-            .sum::<i64>()
-            .to_string()
+            .sum::<AocResult<i64>>()
+            .map(|total| total.to_string())
     }
 
-    fn part_two(parsed_input: &mut Self::ParsedInput) -> String {
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
         parsed_input
             .iter()
             .map(|seq| {
+                let first = *seq.first().ok_or_else(|| AocError::new("Empty sequence"))?;
                 let mut levels = vec![];
                 let mut current_seq = seq.iter().rev().copied().collect::<Vec<_>>();
                 loop {
@@ -116,14 +131,15 @@ impl Solution for Day09 {
                         levels.push(diffs);
                     }
                 }
-                let mut next_for_seq = 0;
-                for level in levels.iter().rev() {
-                    next_for_seq = level.iter().last().unwrap() - next_for_seq;
-                }
-                seq[0] - next_for_seq
+                let next_for_seq = levels
+                    .iter()
+                    .rev()
+                    .filter_map(|level| level.last())
+                    .fold(0, |next, last| last - next);
+                Ok(first - next_for_seq)
             })
-            .sum::<i64>()
-            .to_string()
+            .sum::<AocResult<i64>>()
+            .map(|total| total.to_string())
     }
 }
 
@@ -157,6 +173,14 @@ mod tests {
 
     #[test]
     fn check_day09_both_case1() {
-        assert_eq!(Day09::solve("", false), ("0".to_string(), "0".to_string()))
+        assert_eq!(Day09::solve(""), ("0".to_string(), "0".to_string()))
+    }
+
+    #[test]
+    fn check_day09_empty_sequence() {
+        assert_eq!(
+            Day09::try_solve("1 2\n\n3").unwrap_err().to_string(),
+            "day 9, parsing, line 2: Expected a sequence of numbers"
+        )
     }
 }
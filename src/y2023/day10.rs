@@ -0,0 +1,239 @@
+use petgraph::{algo::dijkstra, stable_graph::NodeIndex, visit::Bfs};
+
+use crate::{
+    error::{AocError, AocResult},
+    grid::Grid,
+    grid_graph::{GraphBuilder, GridGraph},
+    point::Dir4,
+    Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: i32 = 10;
+    const TITLE: &'static str = "Pipe Maze";
+
+    type ParsedInput = (GridGraph<PipeType, ()>, NodeIndex);
+
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        // Change the return type of this function by editing the ParsedInput type above.
+        // You can skip this and pass the raw string to each part.
+        // Alternatively, you can parse the input here, either working on the same mutable struct
+        // in parts one and two or passing a tuple with the data required for each part.
+
+        let pipes = Grid::parse(input_lines, PipeType::from_char)?;
+        let start = pipes
+            .find(|pipe| *pipe == PipeType::Unknown)
+            .ok_or_else(|| AocError::new("No starting position 'S' in the maze"))?;
+
+        let maze = GraphBuilder::new(&pipes)
+            .nodes(|pipe| *pipe != PipeType::NotPipe)
+            .connect(|dir, from, to| from.is_connected(dir, to))
+            .build();
+        let start = maze
+            .node(start)
+            .ok_or_else(|| AocError::new("The start isn't part of the maze"))?;
+        Ok((maze, start))
+    }
+
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        dijkstra(&parsed_input.0.graph, parsed_input.1, None, |_| 1)
+            .into_values()
+            .max()
+            .map(|steps: i32| steps.to_string())
+            .ok_or_else(|| AocError::new("The start isn't part of the maze"))
+    }
+
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        let (maze, start) = parsed_input;
+        Ok(mark_loop(maze, *start)
+            .iter()
+            .filter(|(_, pt)| **pt == PipeType::InLoop)
+            .count()
+            .to_string())
+    }
+}
+
+/// A grid of just the loop through `start`, with the tiles it encloses marked as `InLoop`.
+fn mark_loop(maze: &GridGraph<PipeType, ()>, start: NodeIndex) -> Grid<PipeType> {
+    let mut grid = Grid::new(maze.width(), maze.height(), PipeType::NotPipe);
+    let mut bfs = Bfs::new(&maze.graph, start);
+    while let Some(node_idx) = bfs.next(&maze.graph) {
+        grid[maze.coord(node_idx)] = maze.graph[node_idx];
+    }
+
+    // Credit to reddit for helping me get this algorithm... I'd spent far too long on this puzzle already..
+    let mut currently_in_loop = false;
+    for (x, y) in grid.coords() {
+        if x == 0 {
+            currently_in_loop = false;
+        }
+        let pipe_type = &mut grid[(x, y)];
+        match *pipe_type {
+            PipeType::NotPipe if currently_in_loop => *pipe_type = PipeType::InLoop,
+            PipeType::NE | PipeType::NW | PipeType::Vertical | PipeType::Unknown => {
+                currently_in_loop = !currently_in_loop
+            }
+            _ => (),
+        }
+    }
+    grid
+}
+
+#[derive(Clone, Debug, Copy, Hash, Eq, PartialEq)]
+pub enum PipeType {
+    NotPipe,
+    NE,
+    NW,
+    SE,
+    SW,
+    Horizontal,
+    Vertical,
+    Unknown,
+    InLoop,
+}
+
+impl PipeType {
+    fn from_char(c: char) -> AocResult<PipeType> {
+        match c {
+            '.' => Ok(PipeType::NotPipe),
+            '|' => Ok(PipeType::Vertical),
+            '-' => Ok(PipeType::Horizontal),
+            'L' => Ok(PipeType::NE),
+            'J' => Ok(PipeType::NW),
+            '7' => Ok(PipeType::SW),
+            'F' => Ok(PipeType::SE),
+            'S' => Ok(PipeType::Unknown),
+            input => Err(AocError::new(format!("Unexpected input char: {}", input))),
+        }
+    }
+
+    /// How the pipe looks when drawing the loop, for debugging.
+    #[cfg(test)]
+    fn display_char(&self) -> char {
+        match self {
+            PipeType::NotPipe => '.',
+            PipeType::NE => '└',
+            PipeType::NW => '┘',
+            PipeType::SE => '┌',
+            PipeType::SW => '┐',
+            PipeType::Horizontal => '─',
+            PipeType::Vertical => '│',
+            PipeType::Unknown => 'S',
+            PipeType::InLoop => 'x',
+        }
+    }
+
+    /// Whether this pipe has an opening towards `dir`. The start could be any shape.
+    fn opens(&self, dir: Dir4) -> bool {
+        match self {
+            PipeType::NotPipe => false,
+            PipeType::NE => matches!(dir, Dir4::North | Dir4::East),
+            PipeType::NW => matches!(dir, Dir4::North | Dir4::West),
+            PipeType::SE => matches!(dir, Dir4::South | Dir4::East),
+            PipeType::SW => matches!(dir, Dir4::South | Dir4::West),
+            PipeType::Horizontal => matches!(dir, Dir4::East | Dir4::West),
+            PipeType::Vertical => matches!(dir, Dir4::North | Dir4::South),
+            PipeType::Unknown | PipeType::InLoop => true,
+        }
+    }
+
+    /// Whether this pipe joins up with `other`, the next pipe over towards `dir`.
+    fn is_connected(&self, dir: Dir4, other: &PipeType) -> bool {
+        self.opens(dir) && other.opens(dir.opposite())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draw the loop in `input` with the enclosed tiles marked, to see what part two counted.
+    fn draw_loop(input: &str) -> String {
+        let (maze, start) = Day10::parse_input(input).unwrap();
+        mark_loop(&maze, start)
+            .map(PipeType::display_char)
+            .to_string()
+    }
+
+    #[test]
+    fn check_day10_loop_drawing() {
+        assert_eq!(
+            draw_loop(
+                ".....
+.F-7.
+.|.|.
+.S-J.
+....."
+            ),
+            ".....
+.┌─┐.
+.│x│.
+.S─┘.
+....."
+        )
+    }
+
+    #[test]
+    fn check_day10_part1_case1() {
+        assert_eq!(
+            Day10::solve_part_one(
+                "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF"
+            ),
+            "4".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day10_part1_case2() {
+        assert_eq!(
+            Day10::solve_part_one(
+                "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ"
+            ),
+            "8".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day10_part2_case1() {
+        assert_eq!(
+            Day10::solve_part_two(
+                "-L|F7
+7S-7|
+L|7||
+-L-J|
+L|-JF"
+            ),
+            "1".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day10_part2_case2() {
+        assert_eq!(
+            Day10::solve_part_two(
+                "7-F7-
+.FJ|7
+SJLL7
+|F--J
+LJ.LJ"
+            ),
+            "1".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day10_both_case1() {
+        assert_eq!(Day10::solve(""), ("0".to_string(), "0".to_string()))
+    }
+}
@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+
+use petgraph::algo::dijkstra;
+
+use crate::{
+    error::AocResult,
+    grid::Grid,
+    grid_graph::{GraphBuilder, GridGraph},
+    Solution,
+};
+
+#[derive(Clone, Debug)]
+pub struct Day11;
+
+#[derive(Clone)]
+pub struct Day11Data {
+    universe: Grid<char>,
+    galaxies: Vec<(usize, usize)>,
+    rows_to_expand: Vec<usize>,
+    cols_to_expand: Vec<usize>,
+}
+
+impl Solution for Day11 {
+    const DAY: i32 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
+
+    type ParsedInput = Day11Data;
+
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
+        // Change the return type of this function by editing the ParsedInput type above.
+        // You can skip this and pass the raw string to each part.
+        // Alternatively, you can parse the input here, either working on the same mutable struct
+        // in parts one and two or passing a tuple with the data required for each part.
+        let universe: Grid<char> = input_lines.parse()?;
+
+        let galaxies = universe
+            .iter()
+            .filter(|(_coord, item)| **item == '#')
+            .map(|(coord, _item)| coord)
+            .collect::<Vec<_>>();
+
+        let rows_to_expand = universe
+            .rows()
+            .enumerate()
+            .filter(|(_row_idx, row)| !row.contains(&'#'))
+            .map(|(row_idx, _row)| row_idx)
+            .collect::<Vec<_>>();
+
+        let cols_to_expand = universe
+            .columns()
+            .enumerate()
+            .filter_map(|(col_idx, mut col)| col.all(|item| *item != '#').then_some(col_idx))
+            .collect::<Vec<_>>();
+
+        Ok(Day11Data {
+            universe,
+            galaxies,
+            rows_to_expand,
+            cols_to_expand,
+        })
+    }
+
+    fn part_one(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(solve_for_expansion(parsed_input, 2))
+    }
+
+    fn part_two(parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
+        Ok(solve_for_expansion(parsed_input, 1000000))
+    }
+}
+
+fn solve_for_expansion(parsed_input: &mut Day11Data, expansion_amount: u64) -> String {
+    // Stepping out of an empty row or column into the next one crosses all the expanded space
+    let expands = |from: usize, to: usize, expanding: &[usize]| {
+        from != to && expanding.contains(&from.min(to))
+    };
+    let universe: GridGraph<char, u64> =
+        GraphBuilder::new(&parsed_input.universe).build_weighted(|from, to| {
+            if expands(from.1, to.1, &parsed_input.rows_to_expand)
+                || expands(from.0, to.0, &parsed_input.cols_to_expand)
+            {
+                expansion_amount
+            } else {
+                1
+            }
+        });
+
+    let mut pairings = HashMap::new();
+    for galaxy in parsed_input.galaxies.iter() {
+        let galaxy = universe.node(*galaxy).unwrap();
+        let distances = dijkstra(&universe.graph, galaxy, None, |e| *e.weight());
+        for (target_node, distance) in distances.into_iter() {
+            if universe.graph[target_node] == '#' {
+                pairings.insert((galaxy.min(target_node), galaxy.max(target_node)), distance);
+            }
+        }
+    }
+    pairings.values().sum::<u64>().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_day11_part1_case1() {
+        assert_eq!(
+            Day11::solve_part_one(
+                "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."
+            ),
+            "374".to_string()
+        )
+    }
+
+    #[test]
+    fn check_day11_case2() {
+        assert_eq!(
+            solve_for_expansion(
+                &mut Day11::parse_input(
+                    "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."
+                )
+                .unwrap(),
+                10
+            ),
+            "1030"
+        )
+    }
+
+    #[test]
+    fn check_day11_case3() {
+        assert_eq!(
+            solve_for_expansion(
+                &mut Day11::parse_input(
+                    "...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#....."
+                )
+                .unwrap(),
+                100
+            ),
+            "8410"
+        )
+    }
+
+    #[test]
+    fn check_day11_both_case1() {
+        assert_eq!(Day11::solve(""), ("0".to_string(), "0".to_string()))
+    }
+}
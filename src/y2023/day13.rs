@@ -1,27 +1,30 @@
-use crate::Solution;
+use crate::{error::AocResult, Solution};
 
 #[derive(Clone, Debug)]
 pub struct Day13;
 
 impl Solution for Day13 {
+    const DAY: i32 = 13;
+    const TITLE: &'static str = "Point of Incidence";
+
     type ParsedInput = String;
 
-    fn parse_input(input_lines: &str) -> Self::ParsedInput {
+    fn parse_input(input_lines: &str) -> AocResult<Self::ParsedInput> {
         // Change the return type of this function by editing the ParsedInput type above.
         // You can skip this and pass the raw string to each part.
         // Alternatively, you can parse the input here, either working on the same mutable struct
         // in parts one and two or passing a tuple with the data required for each part.
-        input_lines.to_string()
+        Ok(input_lines.to_string())
     }
 
-    fn part_one(_parsed_input: &mut Self::ParsedInput) -> String {
+    fn part_one(_parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
         // TODO: implement part one
-        0.to_string()
+        Ok(0.to_string())
     }
 
-    fn part_two(_parsed_input: &mut Self::ParsedInput) -> String {
+    fn part_two(_parsed_input: &mut Self::ParsedInput) -> AocResult<String> {
         // TODO: implement part two
-        0.to_string()
+        Ok(0.to_string())
     }
 }
 
@@ -41,6 +44,6 @@ mod tests {
 
     #[test]
     fn check_day13_both_case1() {
-        assert_eq!(Day13::solve("", false), ("0".to_string(), "0".to_string()))
+        assert_eq!(Day13::solve(""), ("0".to_string(), "0".to_string()))
     }
 }